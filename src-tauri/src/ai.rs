@@ -1,3 +1,4 @@
+use crate::command_result::{Classify, CommandResponse, Severity};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tauri_plugin_opener::OpenerExt;
@@ -14,6 +15,26 @@ struct ExplainResponse {
     error: Option<String>,
 }
 
+/// Errors from calling out to the Text Clarifier API.
+#[derive(Debug, thiserror::Error)]
+pub enum ExplainError {
+    #[error("Request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("API error: {0}")]
+    Api(String),
+    #[error("No explanation returned")]
+    Empty,
+}
+
+impl Classify for ExplainError {
+    fn severity(&self) -> Severity {
+        match self {
+            ExplainError::Request(e) => e.severity(),
+            ExplainError::Api(_) | ExplainError::Empty => Severity::Fatal,
+        }
+    }
+}
+
 /// Open the default browser to the Text Clarifier auth page.
 /// The website will redirect back to `textclarifier://auth?token=xxx`
 /// which is handled by the deep-link plugin in lib.rs.
@@ -39,7 +60,15 @@ pub async fn explain_text(
     api_key: String,
     text: String,
     context: String,
-) -> Result<String, String> {
+) -> CommandResponse<String> {
+    explain_text_inner(api_key, text, context).await.into()
+}
+
+async fn explain_text_inner(
+    api_key: String,
+    text: String,
+    context: String,
+) -> Result<String, ExplainError> {
     let client = reqwest::Client::new();
     let res = client
         .post("https://api.textclarifier.com/clarify")
@@ -48,22 +77,17 @@ pub async fn explain_text(
         .json(&ExplainRequest { text, context })
         .timeout(Duration::from_secs(15))
         .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        .await?;
 
     if !res.status().is_success() {
-        return Err(format!("API Error: {}", res.status()));
+        return Err(ExplainError::Api(format!("API Error: {}", res.status())));
     }
 
-    let body = res
-        .json::<ExplainResponse>()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let body = res.json::<ExplainResponse>().await?;
 
     if let Some(error) = body.error {
-        return Err(error);
+        return Err(ExplainError::Api(error));
     }
 
-    body.result
-        .ok_or_else(|| "No explanation returned".to_string())
+    body.result.ok_or(ExplainError::Empty)
 }
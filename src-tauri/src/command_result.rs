@@ -0,0 +1,142 @@
+//! Tagged results for the Tauri command surface.
+//!
+//! Plain `Result<T, String>` tells the frontend a command failed, but not
+//! whether the failure is worth retrying. A dropped connection or a busy
+//! engine should trigger an automatic retry; a corrupt file or a failed
+//! model download should surface a hard error dialog instead. This module
+//! gives commands a typed way to say which is which.
+
+use serde::Serialize;
+
+/// Whether a failure is worth retrying automatically, or needs the user
+/// (or the app's state) to change before it could possibly succeed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Recoverable,
+    Fatal,
+}
+
+/// Result of a Tauri command, serialized as a tagged union so the
+/// TypeScript side can match on `status` instead of pattern-matching an
+/// error string.
+#[derive(Debug, Serialize)]
+#[serde(tag = "status")]
+pub enum CommandResponse<T> {
+    Success {
+        content: T,
+    },
+    /// Recoverable: a retry (possibly after a backoff) may succeed.
+    Failure {
+        message: String,
+    },
+    /// Non-recoverable: won't succeed on retry without something changing.
+    Fatal {
+        message: String,
+    },
+}
+
+/// Classifies an error type into a retryable or non-retryable tier. Each
+/// error enum that reaches the command layer implements this once, so
+/// commands don't have to re-judge the same error case by case.
+pub trait Classify: std::fmt::Display {
+    fn severity(&self) -> Severity;
+}
+
+impl<T, E: Classify> From<Result<T, E>> for CommandResponse<T> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(content) => CommandResponse::Success { content },
+            Err(e) => {
+                let message = e.to_string();
+                match e.severity() {
+                    Severity::Recoverable => CommandResponse::Failure { message },
+                    Severity::Fatal => CommandResponse::Fatal { message },
+                }
+            }
+        }
+    }
+}
+
+impl Classify for crate::tts::AudioError {
+    fn severity(&self) -> Severity {
+        use crate::tts::AudioError::*;
+        match self {
+            // Output stream / playback hiccups are usually transient (device
+            // busy, momentarily unavailable) and worth retrying.
+            StreamError(_) | PlaybackError(_) => Severity::Recoverable,
+            // Bad audio data won't decode any better on a second attempt.
+            DecodeError(_) => Severity::Fatal,
+        }
+    }
+}
+
+impl Classify for crate::epub::ParseError {
+    fn severity(&self) -> Severity {
+        use crate::epub::ParseError::*;
+        match self {
+            // Could be a transient I/O hiccup (file briefly locked, etc).
+            FileError(_) => Severity::Recoverable,
+            // A malformed book or an unsupported extension won't change
+            // on retry without the user picking a different file.
+            EpubError(_) | UnsupportedFormat(_) => Severity::Fatal,
+        }
+    }
+}
+
+impl Classify for crate::tts::EchoError {
+    fn severity(&self) -> Severity {
+        use crate::tts::EchoError::*;
+        match self {
+            // The engine is in use by another stream right now; worth
+            // retrying once that stream finishes.
+            Busy => Severity::Recoverable,
+            // A single generation call can fail transiently (e.g. a
+            // hiccup in the model's internal sampling loop).
+            GenerationError(_) => Severity::Recoverable,
+            // These need an explicit `initialize()` call (and possibly a
+            // successful model download) before anything will work again.
+            InitError(_) | NotInitialized => Severity::Fatal,
+        }
+    }
+}
+
+impl Classify for crate::tts::SystemError {
+    fn severity(&self) -> Severity {
+        use crate::tts::SystemError::*;
+        match self {
+            // The platform's speech engine couldn't be brought up at all
+            // (missing SAPI/Speech Dispatcher install, etc); retrying the
+            // same call won't help.
+            InitError(_) => Severity::Fatal,
+            // A single speak/stop/pause call failing (e.g. the platform API
+            // rejected a transient state change) is usually worth retrying.
+            SpeechError(_) => Severity::Recoverable,
+        }
+    }
+}
+
+impl Classify for crate::tts::ChatterboxError {
+    fn severity(&self) -> Severity {
+        use crate::tts::ChatterboxError::*;
+        match self {
+            // The sidecar process itself died or became unreachable; a
+            // restart (manual or via `auto_restart`) may recover it.
+            ProcessNotRunning | CommunicationError(_) => Severity::Recoverable,
+            // A single generation call can fail transiently.
+            GenerationError(_) => Severity::Recoverable,
+            // These need the sidecar binary/model to actually be fixed
+            // before a retry could succeed.
+            SpawnError(_) | InvalidResponse(_) | SidecarNotFound(_) => Severity::Fatal,
+        }
+    }
+}
+
+impl Classify for reqwest::Error {
+    fn severity(&self) -> Severity {
+        if self.is_timeout() || self.is_connect() {
+            Severity::Recoverable
+        } else {
+            Severity::Fatal
+        }
+    }
+}
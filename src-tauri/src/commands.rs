@@ -1,44 +1,105 @@
 //! Tauri commands for the frontend to interact with the Rust backend
 
-use crate::epub::{Book, Chapter, EpubParser};
-use crate::tts::{AudioPlayer, EchoManager, PlaybackManager, TTSEngine, TtsPlaybackEvent, Voice};
+use crate::command_result::CommandResponse;
+use crate::epub::{Book, Chapter, EpubParser, ParseError};
+use crate::tts::{
+    download_echo_model_files, echo_model_cache_dir, echo_model_download_size,
+    echo_model_missing_files, fetch_echo_model_manifest, AudioFormat, AudioMetadata,
+    CancellationToken, ChatterboxManager, EchoError, EchoManager, MediaControlsBridge,
+    PlaybackManager, ReferenceAudio, SegmentInfo, SegmentWriter, SpatialConfig,
+    StreamLoaderController, SynthOptions, SynthesisParams, SystemManager, TTSEngine, Timeline,
+    TtsPlaybackEvent, TtsScheduler, Voice,
+};
 use std::fs;
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
 use tauri::{Emitter, State};
+use tracing::Instrument;
 
 /// Application state
 pub struct AppState {
-    /// Echo-1B native Rust TTS engine (primary)
+    /// Echo-1B native Rust TTS engine (primary, requires a model download)
     pub echo: Arc<EchoManager>,
-    // Python-based TTS engines temporarily disabled
-    // /// Legacy Python sidecar TTS engine (fallback)
-    // pub tts: Arc<Mutex<ChatterboxManager>>,
+    /// OS-native TTS engine (instant, zero-download fallback)
+    pub system_tts: Arc<SystemManager>,
+    /// Python sidecar TTS engine (Chatterbox/Qwen3), for users who've set up
+    /// the sidecar themselves. `ChatterboxManager` locks its own backend
+    /// internally, so this needs no outer `Mutex` (mirrors `echo`/`system_tts`).
+    pub tts: Arc<ChatterboxManager>,
     /// Currently active TTS engine
     pub current_engine: Arc<Mutex<TTSEngine>>,
     pub audio_speed: Arc<Mutex<f32>>,
     pub current_book: Arc<Mutex<Option<Book>>>,
-    pub playback: Arc<Mutex<Option<PlaybackManager>>>,
+    /// The playback actor's handle. `PlaybackManager` is itself just a cheap,
+    /// `Clone`-able `mpsc::Sender` plus some atomics and a small
+    /// `current_session_id` mutex -- the audio thread it talks to owns all
+    /// the real state -- so once it's built there's no lock around the
+    /// manager itself to contend on command dispatch. `OnceLock` makes "build it lazily, on
+    /// the first command that needs it" race-free without one: every command
+    /// handler calls `get_or_init_playback`, but only the first ever runs the
+    /// initializer, and every caller (including ones that arrive mid-init)
+    /// blocks on that single initialization instead of each building (and
+    /// racing to store) its own manager.
+    pub playback: OnceLock<PlaybackManager>,
+    /// OS media-key / MPRIS bridge, created alongside `playback` on first use.
+    /// Kept here only to hold it alive; it has no public surface beyond setup.
+    pub media_controls: Arc<Mutex<Option<MediaControlsBridge>>>,
+    /// Optional list of model mirror base URLs (self-hosted / S3 / HF mirror).
+    /// When set, downloads try these in order before the default HuggingFace URL.
+    pub model_source: Arc<Mutex<Option<Vec<String>>>>,
+    /// HRIR set directory last selected via `set_spatial_position`, reused on
+    /// later calls that only change azimuth/elevation.
+    pub spatial_hrir_path: Arc<Mutex<Option<PathBuf>>>,
+    /// Sentence-prefetch scheduler for `tts_stream_chapter`, built lazily on
+    /// first use. Wrapped in `Arc` so a command can clone it out of the lock
+    /// and await its (async) methods without holding the lock across an
+    /// await point.
+    pub scheduler: Arc<Mutex<Option<Arc<TtsScheduler>>>>,
+    /// Seek/scrub handle for the `StreamingSource` most recently enqueued by
+    /// `tts_stream_text`, plus its sample rate (needed to convert `tts_seek_stream`'s
+    /// millisecond offset to a sample offset). Replaced wholesale on every new
+    /// stream -- there's only ever one in-flight Echo stream at a time.
+    pub stream_loader: Arc<Mutex<Option<(StreamLoaderController, u32)>>>,
 }
 
 impl AppState {
     pub fn new() -> Self {
         Self {
             echo: Arc::new(EchoManager::new()),
-            // tts: Arc::new(Mutex::new(ChatterboxManager::new())),
+            system_tts: Arc::new(SystemManager::new()),
+            tts: Arc::new(ChatterboxManager::new()),
             current_engine: Arc::new(Mutex::new(TTSEngine::default())),
             audio_speed: Arc::new(Mutex::new(1.0)),
             current_book: Arc::new(Mutex::new(None)),
-            playback: Arc::new(Mutex::new(None)),
+            playback: OnceLock::new(),
+            media_controls: Arc::new(Mutex::new(None)),
+            model_source: Arc::new(Mutex::new(None)),
+            spatial_hrir_path: Arc::new(Mutex::new(None)),
+            scheduler: Arc::new(Mutex::new(None)),
+            stream_loader: Arc::new(Mutex::new(None)),
         }
     }
 
-    fn get_or_init_playback(&self, app: &tauri::AppHandle) -> Result<PlaybackManager, String> {
-        let mut playback = self.playback.lock().map_err(|e| e.to_string())?;
-        if playback.is_none() {
-            *playback = Some(PlaybackManager::new(app.clone()));
-        }
-        Ok(playback.as_ref().unwrap().clone())
+    fn get_or_init_playback(&self, app: &tauri::AppHandle) -> PlaybackManager {
+        self.playback
+            .get_or_init(|| {
+                let manager = PlaybackManager::new(app.clone());
+
+                // Best-effort: a desktop without MPRIS/Now Playing support (or one
+                // missing the platform-specific window handle) shouldn't prevent
+                // playback from working.
+                match MediaControlsBridge::new(app, manager.clone()) {
+                    Ok(bridge) => {
+                        if let Ok(mut media_controls) = self.media_controls.lock() {
+                            *media_controls = Some(bridge);
+                        }
+                    }
+                    Err(e) => eprintln!("[TTS] Media controls unavailable: {}", e),
+                }
+
+                manager
+            })
+            .clone()
     }
 
     fn get_engine(&self) -> Result<TTSEngine, String> {
@@ -63,117 +124,169 @@ pub async fn read_epub_bytes(path: String) -> Result<Vec<u8>, String> {
 
 /// Open and parse a book file
 #[tauri::command]
-pub async fn open_book(path: String, state: State<'_, AppState>) -> Result<Book, String> {
+pub async fn open_book(path: String, state: State<'_, AppState>) -> CommandResponse<Book> {
     let path = PathBuf::from(&path);
 
-    let book = tokio::task::spawn_blocking(move || EpubParser::parse(&path))
-        .await
-        .map_err(|e| format!("Task error: {}", e))?
-        .map_err(|e| e.to_string())?;
+    let result: Result<Book, ParseError> = async {
+        let book = tokio::task::spawn_blocking(move || EpubParser::parse(&path))
+            .await
+            .map_err(|e| ParseError::EpubError(format!("Task error: {}", e)))??;
 
-    let mut current = state.current_book.lock().map_err(|e| e.to_string())?;
-    *current = Some(book.clone());
+        let mut current = state
+            .current_book
+            .lock()
+            .map_err(|e| ParseError::EpubError(e.to_string()))?;
+        *current = Some(book.clone());
 
-    Ok(book)
+        Ok(book)
+    }
+    .await;
+
+    result.into()
 }
 
 /// Get the currently loaded book
 #[tauri::command]
-pub fn get_current_book(state: State<'_, AppState>) -> Result<Option<Book>, String> {
-    let current = state.current_book.lock().map_err(|e| e.to_string())?;
-    Ok(current.clone())
+pub fn get_current_book(state: State<'_, AppState>) -> CommandResponse<Option<Book>> {
+    // A poisoned lock means some other command panicked while holding it --
+    // not something a retry can fix.
+    match state.current_book.lock() {
+        Ok(current) => CommandResponse::Success {
+            content: current.clone(),
+        },
+        Err(e) => CommandResponse::Fatal {
+            message: e.to_string(),
+        },
+    }
 }
 
 /// Get a specific chapter
 #[tauri::command]
-pub fn get_chapter(index: usize, state: State<'_, AppState>) -> Result<Option<Chapter>, String> {
-    let current = state.current_book.lock().map_err(|e| e.to_string())?;
+pub fn get_chapter(index: usize, state: State<'_, AppState>) -> CommandResponse<Option<Chapter>> {
+    match state.current_book.lock() {
+        Ok(current) => CommandResponse::Success {
+            content: current
+                .as_ref()
+                .and_then(|book| book.chapters.get(index).cloned()),
+        },
+        Err(e) => CommandResponse::Fatal {
+            message: e.to_string(),
+        },
+    }
+}
+
+/// Speak text using the Chatterbox/Qwen3 Python sidecar TTS engine.
+///
+/// Unlike `tts_stream_text`, which streams the sidecar's output chunk by
+/// chunk via `generate_stream`, this synthesizes the whole utterance up
+/// front, then blocks on playing it back.
+#[tauri::command]
+pub async fn speak(
+    text: String,
+    _voice: String, // Chatterbox uses its own voice
+    speed: f32,
+    exaggeration: Option<f32>,
+    cfg_weight: Option<f32>,
+    seed: Option<u64>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let engine = state.get_engine()?;
+    if !matches!(engine, TTSEngine::Chatterbox | TTSEngine::Qwen3TTS) {
+        return Err(format!(
+            "speak() only supports the Chatterbox/Qwen3TTS sidecar engines, current engine is {:?}",
+            engine
+        ));
+    }
+
+    println!(
+        "[TTS] speak called with text length: {}, speed: {}",
+        text.len(),
+        speed
+    );
+
+    let tts = Arc::clone(&state.tts);
+    let wav_data = tokio::task::spawn_blocking(move || -> Result<Vec<u8>, String> {
+        // Start the TTS process if not running
+        if !tts.is_initialized() {
+            println!("[TTS] Starting Chatterbox TTS...");
+            tts.start().map_err(|e| {
+                let err = format!("Failed to start TTS: {}", e);
+                eprintln!("[TTS] {}", err);
+                err
+            })?;
+
+            tts.init_model().map_err(|e| {
+                let err = format!("Failed to init model: {}", e);
+                eprintln!("[TTS] {}", err);
+                err
+            })?;
+        }
 
-    if let Some(book) = current.as_ref() {
-        Ok(book.chapters.get(index).cloned())
-    } else {
-        Ok(None)
+        println!("[TTS] Generating audio with Chatterbox...");
+
+        let defaults = SynthOptions::default();
+        let options = SynthOptions {
+            speed,
+            exaggeration: exaggeration.unwrap_or(defaults.exaggeration),
+            cfg_weight: cfg_weight.unwrap_or(defaults.cfg_weight),
+            seed,
+            ..defaults
+        };
+        let audio = tts.generate(&text, &options).map_err(|e| {
+            let err = format!("Generation error: {}", e);
+            eprintln!("[TTS] {}", err);
+            err
+        })?;
+
+        println!("[TTS] Audio generated, {} samples", audio.audio.len());
+        Ok(audio.to_wav())
+    })
+    .await
+    .map_err(|e| format!("Task error: {}", e))??;
+
+    println!(
+        "[TTS] WAV data size: {} bytes, starting playback...",
+        wav_data.len()
+    );
+
+    // Play audio
+    let play_result = tokio::task::spawn_blocking(move || crate::tts::play_wav_blocking(wav_data))
+        .await
+        .map_err(|e| {
+            let err = format!("Task error: {}", e);
+            eprintln!("[TTS] {}", err);
+            err
+        })?;
+
+    match &play_result {
+        Ok(_) => println!("[TTS] Playback completed successfully"),
+        Err(e) => eprintln!("[TTS] Playback error: {}", e),
     }
+
+    play_result.map_err(|e| e.to_string())
 }
 
-// ============================================================================
-// Python-based TTS functions temporarily disabled
-// ============================================================================
+/// Set a reference audio clip for Chatterbox voice cloning, used by
+/// subsequent `speak`/`tts_stream_text` calls until cleared. `path` must
+/// point at a WAV file; engines without `features().voice_cloning` return
+/// an error.
+#[tauri::command]
+pub fn set_chatterbox_reference_voice(
+    path: String,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    state
+        .tts
+        .set_reference_voice(ReferenceAudio::Path(PathBuf::from(path)))
+        .into()
+}
 
-// /// Speak text using Chatterbox TTS
-// #[tauri::command]
-// pub async fn speak(
-//     text: String,
-//     _voice: String, // Chatterbox uses its own voice
-//     speed: f32,
-//     state: State<'_, AppState>,
-// ) -> Result<(), String> {
-//     println!(
-//         "[TTS] speak called with text length: {}, speed: {}",
-//         text.len(),
-//         speed
-//     );
-//
-//     // Generate audio using Chatterbox
-//     let wav_data = {
-//         let tts = state.tts.lock().map_err(|e| {
-//             let err = format!("Lock error: {}", e);
-//             eprintln!("[TTS] {}", err);
-//             err
-//         })?;
-//
-//         // Start the TTS process if not running
-//         if !tts.is_initialized() {
-//             println!("[TTS] Starting Chatterbox TTS...");
-//             tts.start().map_err(|e| {
-//                 let err = format!("Failed to start TTS: {}", e);
-//                 eprintln!("[TTS] {}", err);
-//                 err
-//             })?;
-//
-//             tts.init_model().map_err(|e| {
-//                 let err = format!("Failed to init model: {}", e);
-//                 eprintln!("[TTS] {}", err);
-//                 err
-//             })?;
-//         }
-//
-//         println!("[TTS] Generating audio with Chatterbox...");
-//
-//         let audio = tts.generate(&text, speed).map_err(|e| {
-//             let err = format!("Generation error: {}", e);
-//             eprintln!("[TTS] {}", err);
-//             err
-//         })?;
-//
-//         println!("[TTS] Audio generated, {} samples", audio.audio.len());
-//         audio.to_wav()
-//     };
-//
-//     println!(
-//         "[TTS] WAV data size: {} bytes, starting playback...",
-//         wav_data.len()
-//     );
-//
-//     // Play audio
-//     let play_result = tokio::task::spawn_blocking(move || {
-//         let player = AudioPlayer::new();
-//         player.play_wav_blocking(wav_data)
-//     })
-//     .await
-//     .map_err(|e| {
-//         let err = format!("Task error: {}", e);
-//         eprintln!("[TTS] {}", err);
-//         err
-//     })?;
-//
-//     match &play_result {
-//         Ok(_) => println!("[TTS] Playback completed successfully"),
-//         Err(e) => eprintln!("[TTS] Playback error: {}", e),
-//     }
-//
-//     play_result.map_err(|e| e.to_string())
-// }
+/// Stop using a reference voice set via `set_chatterbox_reference_voice`,
+/// returning to the engine's default speaker.
+#[tauri::command]
+pub fn clear_chatterbox_reference_voice(state: State<'_, AppState>) -> CommandResponse<()> {
+    state.tts.clear_reference_voice().into()
+}
 
 // ============================================================================
 // Chunked / queued TTS commands (non-blocking)
@@ -186,7 +299,7 @@ pub fn tts_start_session(
     app: tauri::AppHandle,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
-    let playback = state.get_or_init_playback(&app)?;
+    let playback = state.get_or_init_playback(&app);
     playback.start_session(session_id);
     Ok(())
 }
@@ -213,114 +326,405 @@ pub async fn tts_enqueue_chunk(
 /// Unlike `tts_enqueue_chunk`, this sends the entire text to the model in one call
 /// and streams audio frames directly to playback as they're generated.
 /// No sentence splitting needed -- the model handles the full text.
+///
+/// The above is the Echo path. With the System engine the OS speaks the text
+/// itself; with Chatterbox/Qwen3 the text is split into chunks of up to 500
+/// characters and each decoded chunk is enqueued as it arrives.
+#[tracing::instrument(skip(text, app, state), fields(text_len = text.len()))]
 #[tauri::command]
 pub async fn tts_stream_text(
     session_id: String,
     text: String,
     _voice: String,
     speed: f32,
+    chapter_index: Option<usize>,
     app: tauri::AppHandle,
     state: State<'_, AppState>,
-) -> Result<(), String> {
+) -> CommandResponse<()> {
     if text.trim().is_empty() {
-        return Ok(());
+        return CommandResponse::Success { content: () };
     }
 
-    let playback = state.get_or_init_playback(&app)?;
-    let echo = Arc::clone(&state.echo);
-    let app_handle = app.clone();
-
-    tauri::async_runtime::spawn(async move {
-        // Session check
-        if let Ok(current) = playback.current_session_id.lock() {
-            if current.as_ref() != Some(&session_id) {
-                return;
-            }
-        }
+    let engine = match state.get_engine() {
+        Ok(engine) => engine,
+        Err(e) => return CommandResponse::Fatal { message: e },
+    };
 
-        // Initialize Echo if needed (downloads model on first call)
-        if !echo.is_initialized() {
-            println!("[Echo] Initializing model...");
-            if let Err(e) = echo.initialize().await {
-                eprintln!("[Echo] Init error: {}", e);
+    // The OS engine speaks through its own playback, entirely bypassing
+    // Echo/StreamingSource/PlaybackManager -- there's nothing to enqueue.
+    if engine == TTSEngine::System {
+        let system_tts = Arc::clone(&state.system_tts);
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            if let Err(e) = system_tts.speak(&text, speed) {
+                eprintln!("[System TTS] Speech error: {}", e);
                 let _ = app_handle.emit(
                     "tts-playback-event",
                     TtsPlaybackEvent {
                         session_id: session_id.clone(),
                         chunk_index: 0,
                         event: "generation_error".to_string(),
-                        message: Some(format!("Echo init failed: {}", e)),
+                        message: Some(format!("System TTS failed: {}", e)),
                     },
                 );
-                return;
+            }
+        });
+        return CommandResponse::Success { content: () };
+    }
+
+    // The sidecar has no single-shot streaming source type like Echo's
+    // `StreamingSource` -- instead `generate_stream` hands back complete
+    // per-chunk WAVs as they decode, so each is enqueued as its own chunk
+    // rather than one `enqueue_stream` call.
+    if matches!(engine, TTSEngine::Chatterbox | TTSEngine::Qwen3TTS) {
+        let playback = state.get_or_init_playback(&app);
+        let tts = Arc::clone(&state.tts);
+        let app_handle = app.clone();
+        let session_for_task = session_id.clone();
+
+        tokio::task::spawn_blocking(move || {
+            if !tts.is_initialized() {
+                if let Err(e) = tts.start().and_then(|_| tts.init_model().map(|_| ())) {
+                    eprintln!("[TTS] Chatterbox init failed: {}", e);
+                    let _ = app_handle.emit(
+                        "tts-playback-event",
+                        TtsPlaybackEvent {
+                            session_id: session_for_task.clone(),
+                            chunk_index: 0,
+                            event: "generation_error".to_string(),
+                            message: Some(format!("Chatterbox init failed: {}", e)),
+                        },
+                    );
+                    return;
+                }
+            }
+
+            let options = SynthOptions {
+                speed,
+                ..Default::default()
+            };
+            // Sentence-ish chunk size: big enough to keep prosody across a
+            // few sentences, small enough that the first chunk decodes
+            // quickly instead of after the whole document.
+            const MAX_CHARS: usize = 500;
+            tts.generate_stream(
+                &text,
+                options,
+                MAX_CHARS,
+                CancellationToken::new(),
+                move |seq, result| {
+                    playback.enqueue_wav(session_for_task.clone(), seq, result.to_wav(), speed);
+                },
+            );
+        });
+
+        return CommandResponse::Success { content: () };
+    }
+
+    let playback = state.get_or_init_playback(&app);
+
+    // Reject a call that's already stale (superseded by a newer session
+    // before generation even started) up front, instead of silently
+    // no-oping once it's discovered deep inside the spawned task below --
+    // the frontend can retry with its current session immediately.
+    let current = playback
+        .current_session_id
+        .lock()
+        .ok()
+        .and_then(|id| id.clone());
+    if current.as_ref() != Some(&session_id) {
+        return CommandResponse::Failure {
+            message: format!(
+                "Session {} was superseded before generation started",
+                session_id
+            ),
+        };
+    }
+
+    let echo = Arc::clone(&state.echo);
+    let stream_loader = Arc::clone(&state.stream_loader);
+    let app_handle = app.clone();
+
+    // Words for the chapter this text came from, if the caller told us which
+    // one -- used to build a real karaoke-highlighting timeline below rather
+    // than leaving highlighting permanently disabled.
+    let chapter_words = chapter_index.and_then(|index| {
+        state
+            .current_book
+            .lock()
+            .ok()
+            .and_then(|book| book.as_ref().and_then(|b| b.chapters.get(index).cloned()))
+            .map(|chapter| chapter.words)
+    });
+
+    // `.in_current_span()` re-attaches this command's instrumented span to
+    // the spawned task -- `async_runtime::spawn` otherwise loses it, since
+    // the task is polled independently of the command that started it.
+    tauri::async_runtime::spawn(
+        async move {
+            // Session check
+            if let Ok(current) = playback.current_session_id.lock() {
+                if current.as_ref() != Some(&session_id) {
+                    return;
+                }
+            }
+
+            // Initialize Echo if needed (downloads model on first call)
+            if !echo.is_initialized() {
+                tracing::info!("initializing Echo model");
+                if let Err(e) = echo.initialize().await {
+                    tracing::error!(error = %e, "Echo init failed");
+                    let _ = app_handle.emit(
+                        "tts-playback-event",
+                        TtsPlaybackEvent {
+                            session_id: session_id.clone(),
+                            chunk_index: 0,
+                            event: "generation_error".to_string(),
+                            message: Some(format!("Echo init failed: {}", e)),
+                        },
+                    );
+                    return;
+                }
+            }
+
+            // Session check again after potentially long init/model download
+            if let Ok(current) = playback.current_session_id.lock() {
+                if current.as_ref() != Some(&session_id) {
+                    tracing::info!("session cancelled during init");
+                    return;
+                }
+            }
+
+            // Build a karaoke-highlighting timeline from the chapter's real
+            // words, if the caller told us which chapter this text is from.
+            // The schedule is only an estimate at this point (generation
+            // hasn't produced a sample count yet), built from a rough
+            // chars-per-second rate.
+            let timeline = match &chapter_words {
+                Some(words) if !words.is_empty() => {
+                    Some(Timeline::estimated(words, echo.sample_rate().await))
+                }
+                _ => None,
+            };
+
+            // Generate streaming audio -- returns immediately with a StreamingSource
+            match echo.generate_streaming(&text, 0, 0.7, speed).await {
+                Ok((source, controller)) => {
+                    if let Ok(mut slot) = stream_loader.lock() {
+                        *slot = Some((controller, echo.sample_rate().await));
+                    }
+                    // Enqueue as chunk_index=0 (single streaming source for full text)
+                    playback.enqueue_stream(session_id.clone(), 0, source, speed, timeline);
+                    tracing::info!("streaming source enqueued");
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Echo generation failed");
+                    let _ = app_handle.emit(
+                        "tts-playback-event",
+                        TtsPlaybackEvent {
+                            session_id: session_id.clone(),
+                            chunk_index: 0,
+                            event: "generation_error".to_string(),
+                            message: Some(format!("Echo generation failed: {}", e)),
+                        },
+                    );
+                }
             }
         }
+        .in_current_span(),
+    );
+
+    CommandResponse::Success { content: () }
+}
+
+/// Directory the sentence-prefetch cache writes completed units to.
+fn scheduler_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("kokoro-reader")
+        .join("tts-scheduler")
+}
+
+/// Stream a chapter through Echo-1B sentence by sentence, synthesizing
+/// ahead of playback and reusing an on-disk cache so re-reads and seeks
+/// within an already-read chapter are instant.
+///
+/// Unlike `tts_stream_text`, this only supports the Echo engine (the
+/// scheduler's cache is keyed on Echo's own generation parameters) and
+/// takes a `chapter_index` into the open book rather than raw text, so it
+/// always has the chapter's real `Word` list for karaoke highlighting.
+#[tauri::command]
+pub async fn tts_stream_chapter(
+    session_id: String,
+    chapter_index: usize,
+    speed: f32,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    if state.get_engine().unwrap_or_default() != TTSEngine::Echo {
+        return CommandResponse::Failure {
+            message: "tts_stream_chapter only supports the Echo engine".to_string(),
+        };
+    }
 
-        // Session check again after potentially long init/model download
-        if let Ok(current) = playback.current_session_id.lock() {
-            if current.as_ref() != Some(&session_id) {
-                println!("[Echo] Session cancelled during init");
-                return;
+    let chapter = {
+        let current = match state.current_book.lock() {
+            Ok(current) => current,
+            Err(e) => {
+                return CommandResponse::Fatal {
+                    message: e.to_string(),
+                }
+            }
+        };
+        match current.as_ref().and_then(|b| b.chapters.get(chapter_index)) {
+            Some(chapter) => chapter.clone(),
+            None => {
+                return CommandResponse::Failure {
+                    message: format!("No chapter at index {}", chapter_index),
+                }
             }
         }
+    };
 
-        // Generate streaming audio -- returns immediately with a StreamingSource
-        match echo.generate_streaming(&text, 0, 0.7, speed).await {
-            Ok(source) => {
-                // Enqueue as chunk_index=0 (single streaming source for full text)
-                playback.enqueue_streaming(session_id.clone(), 0, source, speed);
-                println!(
-                    "[Echo] Streaming source enqueued for session {}",
-                    &session_id[..8.min(session_id.len())]
-                );
+    let playback = state.get_or_init_playback(&app);
+    playback.start_session(session_id.clone());
+
+    let echo = Arc::clone(&state.echo);
+    let app_handle = app.clone();
+    let scheduler_lock = Arc::clone(&state.scheduler);
+
+    tauri::async_runtime::spawn(
+        async move {
+            if !echo.is_initialized() {
+                if let Err(e) = echo.initialize().await {
+                    let _ = app_handle.emit(
+                        "tts-playback-event",
+                        TtsPlaybackEvent {
+                            session_id: session_id.clone(),
+                            chunk_index: 0,
+                            event: "generation_error".to_string(),
+                            message: Some(format!("Echo init failed: {}", e)),
+                        },
+                    );
+                    return;
+                }
             }
-            Err(e) => {
-                eprintln!("[Echo] Generation error: {}", e);
-                let _ = app_handle.emit(
-                    "tts-playback-event",
-                    TtsPlaybackEvent {
-                        session_id: session_id.clone(),
-                        chunk_index: 0,
-                        event: "generation_error".to_string(),
-                        message: Some(format!("Echo generation failed: {}", e)),
-                    },
-                );
+
+            let scheduler = {
+                let existing = scheduler_lock.lock().ok().and_then(|guard| guard.clone());
+                match existing {
+                    Some(scheduler) => scheduler,
+                    None => {
+                        let scheduler = Arc::new(TtsScheduler::new(
+                            Arc::clone(&echo),
+                            scheduler_cache_dir(),
+                            SynthesisParams {
+                                speaker_id: 0,
+                                temperature: 0.7,
+                                sample_rate: echo.sample_rate().await,
+                            },
+                        ));
+                        if let Ok(mut guard) = scheduler_lock.lock() {
+                            *guard = Some(Arc::clone(&scheduler));
+                        }
+                        scheduler
+                    }
+                }
+            };
+
+            scheduler.load_chapter(&chapter).await;
+
+            let unit_count = scheduler.unit_count().await;
+            for unit_index in 0..unit_count {
+                if playback
+                    .current_session_id
+                    .lock()
+                    .ok()
+                    .and_then(|id| id.clone())
+                    != Some(session_id.clone())
+                {
+                    return;
+                }
+                match scheduler.current(unit_index).await {
+                    Ok(result) => {
+                        playback.enqueue_wav(
+                            session_id.clone(),
+                            unit_index,
+                            result.to_wav(),
+                            speed,
+                        );
+                    }
+                    Err(e) => {
+                        let _ = app_handle.emit(
+                            "tts-playback-event",
+                            TtsPlaybackEvent {
+                                session_id: session_id.clone(),
+                                chunk_index: unit_index,
+                                event: "generation_error".to_string(),
+                                message: Some(format!("Echo generation failed: {}", e)),
+                            },
+                        );
+                        return;
+                    }
+                }
             }
         }
-    });
+        .in_current_span(),
+    );
 
-    Ok(())
+    CommandResponse::Success { content: () }
 }
 
 /// Stop current playback and clear the queue.
 #[tauri::command]
-pub fn tts_stop(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+pub fn tts_stop(app: tauri::AppHandle, state: State<'_, AppState>) -> CommandResponse<()> {
     println!("[TTS] Stop command received - clearing session");
-    let playback = state.get_or_init_playback(&app)?;
+    let engine = match state.get_engine() {
+        Ok(engine) => engine,
+        Err(e) => return CommandResponse::Fatal { message: e },
+    };
+    if engine == TTSEngine::System {
+        return state.system_tts.stop().into();
+    }
+    let playback = state.get_or_init_playback(&app);
     playback.stop();
-    Ok(())
+    CommandResponse::Success { content: () }
 }
 
 /// Pause current playback.
 #[tauri::command]
-pub fn tts_pause(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    let playback = state.get_or_init_playback(&app)?;
+pub fn tts_pause(app: tauri::AppHandle, state: State<'_, AppState>) -> CommandResponse<()> {
+    let engine = match state.get_engine() {
+        Ok(engine) => engine,
+        Err(e) => return CommandResponse::Fatal { message: e },
+    };
+    if engine == TTSEngine::System {
+        return state.system_tts.pause().into();
+    }
+    let playback = state.get_or_init_playback(&app);
     playback.pause();
-    Ok(())
+    CommandResponse::Success { content: () }
 }
 
 /// Resume current playback.
 #[tauri::command]
-pub fn tts_resume(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    let playback = state.get_or_init_playback(&app)?;
+pub fn tts_resume(app: tauri::AppHandle, state: State<'_, AppState>) -> CommandResponse<()> {
+    let engine = match state.get_engine() {
+        Ok(engine) => engine,
+        Err(e) => return CommandResponse::Fatal { message: e },
+    };
+    if engine == TTSEngine::System {
+        return state.system_tts.resume().into();
+    }
+    let playback = state.get_or_init_playback(&app);
     playback.resume();
-    Ok(())
+    CommandResponse::Success { content: () }
 }
 
 /// Stop TTS playback
 #[tauri::command]
 pub fn stop_speaking(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    let playback = state.get_or_init_playback(&app)?;
+    let playback = state.get_or_init_playback(&app);
     playback.stop();
     Ok(())
 }
@@ -328,7 +732,7 @@ pub fn stop_speaking(app: tauri::AppHandle, state: State<'_, AppState>) -> Resul
 /// Pause TTS playback
 #[tauri::command]
 pub fn pause_speaking(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    let playback = state.get_or_init_playback(&app)?;
+    let playback = state.get_or_init_playback(&app);
     playback.pause();
     Ok(())
 }
@@ -336,7 +740,7 @@ pub fn pause_speaking(app: tauri::AppHandle, state: State<'_, AppState>) -> Resu
 /// Resume TTS playback
 #[tauri::command]
 pub fn resume_speaking(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    let playback = state.get_or_init_playback(&app)?;
+    let playback = state.get_or_init_playback(&app);
     playback.resume();
     Ok(())
 }
@@ -349,88 +753,489 @@ pub fn set_speed(speed: f32, state: State<'_, AppState>) -> Result<(), String> {
     Ok(())
 }
 
+/// Enable or disable binaural HRTF rendering of TTS playback at a given
+/// virtual position.
+///
+/// `hrir_path` selects the HRIR set directory; pass it once to select a set
+/// and omit it on later calls (e.g. just adjusting azimuth/elevation) to
+/// keep reusing the one already configured. Disabling (`enabled: false`)
+/// passes audio through unchanged.
+#[tauri::command]
+pub fn set_spatial_position(
+    azimuth: f32,
+    elevation: f32,
+    enabled: bool,
+    hrir_path: Option<String>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    let playback = state.get_or_init_playback(&app);
+
+    if !enabled {
+        playback.set_spatial(None);
+        return CommandResponse::Success { content: () };
+    }
+
+    let hrir_path = match hrir_path
+        .map(PathBuf::from)
+        .or_else(|| state.spatial_hrir_path.lock().ok().and_then(|p| p.clone()))
+    {
+        Some(path) => path,
+        None => {
+            return CommandResponse::Failure {
+                message: "No HRIR set configured; pass hrir_path once to select one".to_string(),
+            }
+        }
+    };
+
+    if let Ok(mut stored) = state.spatial_hrir_path.lock() {
+        *stored = Some(hrir_path.clone());
+    }
+
+    playback.set_spatial(Some(SpatialConfig {
+        azimuth,
+        elevation,
+        hrir_path,
+    }));
+    CommandResponse::Success { content: () }
+}
+
+/// Start recording the session's playback to a single continuous audio
+/// file, fed from the same in-order chunk path that feeds the sink. WAV
+/// chunks are captured as played; streamed chunks are not captured and
+/// leave a gap in the recording.
+#[tauri::command]
+pub fn tts_start_recording(
+    path: String,
+    format: AudioFormat,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    let playback = state.get_or_init_playback(&app);
+    playback.start_recording(PathBuf::from(path), format);
+    CommandResponse::Success { content: () }
+}
+
+/// Stop the active recording and encode what was captured so far. Emits
+/// `recording_finished` (or `error` on failure) with the final path.
+#[tauri::command]
+pub fn tts_stop_recording(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    let playback = state.get_or_init_playback(&app);
+    playback.stop_recording();
+    CommandResponse::Success { content: () }
+}
+
+/// List output device names the chosen backend can route audio to.
+#[tauri::command]
+pub fn list_audio_devices() -> Vec<String> {
+    crate::tts::list_devices()
+}
+
+/// Switch the audio output backend (and optionally device) used by the
+/// current and future playback sessions.
+///
+/// `name` selects a backend from [`crate::tts::BACKENDS`] (falling back to
+/// the default `rodio` backend if unrecognized). `device` is the output
+/// device name for `rodio`, the output file path (or `-`/`stdout`) for
+/// `pipe`, and the command line to run for `subprocess`.
+#[tauri::command]
+pub fn set_audio_backend(
+    name: String,
+    device: Option<String>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    let playback = state.get_or_init_playback(&app);
+    playback.set_backend(name, device);
+    CommandResponse::Success { content: () }
+}
+
 /// Check if audio is playing
 #[tauri::command]
 pub fn is_playing(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<bool, String> {
-    let playback = state.get_or_init_playback(&app)?;
+    if state.get_engine()? == TTSEngine::System {
+        return Ok(state.system_tts.is_speaking());
+    }
+    let playback = state.get_or_init_playback(&app);
     Ok(playback.is_playing())
 }
 
 /// Check if audio is paused
 #[tauri::command]
 pub fn is_paused(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<bool, String> {
-    let playback = state.get_or_init_playback(&app)?;
+    if state.get_engine()? == TTSEngine::System {
+        // The `tts` crate has no "is paused" query; `pause()` falls back to
+        // a full stop on most backends, so there's no mid-utterance paused
+        // state to report here either.
+        return Ok(false);
+    }
+    let playback = state.get_or_init_playback(&app);
     Ok(playback.is_paused())
 }
 
+/// Seek to a global playback time (in milliseconds) across all queued
+/// chunks. Seeking past the last generated chunk clamps to its end; seeking
+/// while paused leaves playback paused.
+#[tauri::command]
+pub fn tts_seek(
+    position_ms: u64,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<()> {
+    let playback = state.get_or_init_playback(&app);
+    playback.seek(std::time::Duration::from_millis(position_ms));
+    CommandResponse::Success { content: () }
+}
+
+/// Current global playback position, in milliseconds.
+#[tauri::command]
+pub fn tts_position(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<u64, String> {
+    let playback = state.get_or_init_playback(&app);
+    Ok(playback.position().as_millis() as u64)
+}
+
+/// Scrub the in-flight Echo `StreamingSource` (from the most recent
+/// `tts_stream_text` call) to `position_ms`, distinct from `tts_seek`: that
+/// one seeks across already-generated, complete WAV chunks, while this seeks
+/// *inside* a single still-generating stream via its `StreamLoaderController`.
+/// A no-op if no stream is currently active.
+#[tauri::command]
+pub fn tts_seek_stream(position_ms: u64, state: State<'_, AppState>) -> CommandResponse<()> {
+    let Ok(slot) = state.stream_loader.lock() else {
+        return CommandResponse::Success { content: () };
+    };
+    if let Some((controller, sample_rate)) = slot.as_ref() {
+        let sample_offset = (position_ms as u128 * *sample_rate as u128 / 1000) as usize;
+        controller.seek(sample_offset);
+    }
+    CommandResponse::Success { content: () }
+}
+
 /// Get available TTS voices
 #[tauri::command]
 pub fn get_voices(state: State<'_, AppState>) -> Vec<Voice> {
     let engine = state.get_engine().unwrap_or_default();
-    Voice::get_voices(engine)
+    if engine == TTSEngine::System {
+        return state.system_tts.list_voices().unwrap_or_default();
+    }
+    Voice::get_voices()
 }
 
 /// Set the TTS engine
 #[tauri::command]
-pub async fn set_tts_engine(engine: String, state: State<'_, AppState>) -> Result<(), String> {
-    // Only Echo engine is currently supported
+pub async fn set_tts_engine(engine: String, state: State<'_, AppState>) -> CommandResponse<()> {
     let tts_engine = match engine.to_lowercase().as_str() {
         "echo" | "echo-1b" | "echo1b" => TTSEngine::Echo,
-        // Python-based engines temporarily disabled
-        // "qwen3" | "qwen3tts" | "qwen3-tts" | "qwen" => TTSEngine::Qwen3TTS,
-        // "chatterbox" => TTSEngine::Chatterbox,
-        _ => TTSEngine::Echo,
+        "system" | "os" | "native" => TTSEngine::System,
+        "qwen3" | "qwen3tts" | "qwen3-tts" | "qwen" => TTSEngine::Qwen3TTS,
+        "chatterbox" => TTSEngine::Chatterbox,
+        _ => TTSEngine::System,
     };
 
-    let current = state.get_engine()?;
+    let current = match state.get_engine() {
+        Ok(current) => current,
+        Err(e) => return CommandResponse::Fatal { message: e },
+    };
     if current == tts_engine {
-        return Ok(());
+        return CommandResponse::Success { content: () };
     }
 
-    // Shutdown the old engine (only Echo is currently active)
-    state.echo.shutdown().await;
+    // Echo may still be downloading/warming in the background while the
+    // user reads with System; only release it when switching to something
+    // else that actually needs its resources back.
+    if current == TTSEngine::Echo && tts_engine != TTSEngine::System {
+        state.echo.shutdown().await;
+    }
+
+    // The sidecar holds a live child process; shut it down when leaving it,
+    // and (re)point it at the right backend when switching into it.
+    if matches!(current, TTSEngine::Chatterbox | TTSEngine::Qwen3TTS) {
+        state.tts.shutdown();
+    }
+    if matches!(tts_engine, TTSEngine::Chatterbox | TTSEngine::Qwen3TTS) {
+        if let Err(e) = state.tts.set_engine(tts_engine) {
+            return Err(e).into();
+        }
+        // The sidecar is a separate OS process we don't control the stability
+        // of -- recover from a crash transparently instead of surfacing it as
+        // a generation error on whatever request happened to be in flight.
+        state.tts.set_auto_restart(true);
+    }
 
     // Update current engine
     {
-        let mut eng = state.current_engine.lock().map_err(|e| e.to_string())?;
+        let mut eng = match state.current_engine.lock() {
+            Ok(eng) => eng,
+            Err(e) => {
+                return CommandResponse::Fatal {
+                    message: e.to_string(),
+                }
+            }
+        };
         *eng = tts_engine;
     }
 
     println!("[TTS] Switched to engine: {:?}", tts_engine);
-    Ok(())
+    CommandResponse::Success { content: () }
+}
+
+/// Change the backend's tracing verbosity at runtime.
+///
+/// `directive` is either a bare level (`"trace"`/`"debug"`/`"info"`/
+/// `"warn"`/`"error"`) or a full `EnvFilter` directive string for
+/// per-module control (e.g. `"warn,kokoro_reader_lib::tts=debug"`), applied
+/// immediately to the subscriber installed in `diagnostics::init`.
+#[tauri::command]
+pub fn set_log_level(directive: String) -> CommandResponse<()> {
+    match crate::diagnostics::set_log_level(&directive) {
+        Ok(()) => CommandResponse::Success { content: () },
+        Err(message) => CommandResponse::Failure { message },
+    }
 }
 
 /// Get the current TTS engine
 #[tauri::command]
 pub fn get_tts_engine(state: State<'_, AppState>) -> String {
-    // Only Echo is currently supported
     match state.get_engine().unwrap_or_default() {
         TTSEngine::Echo => "Echo".to_string(),
+        TTSEngine::System => "System".to_string(),
         // Python-based engines temporarily disabled
-        // TTSEngine::Chatterbox => "Chatterbox".to_string(),
-        // TTSEngine::Qwen3TTS => "Qwen3TTS".to_string(),
+        TTSEngine::Chatterbox => "Chatterbox".to_string(),
+        TTSEngine::Qwen3TTS => "Qwen3TTS".to_string(),
     }
 }
 
 /// Trigger TTS warmup (optional - called when user has enabled warmup in settings)
 #[tauri::command]
-pub async fn tts_warmup(state: State<'_, AppState>) -> Result<bool, String> {
+pub async fn tts_warmup(state: State<'_, AppState>) -> CommandResponse<bool> {
     println!("[TTS] Warmup requested by frontend...");
 
-    // Only Echo engine is currently active
+    let engine = state.get_engine().unwrap_or_default();
+    if matches!(engine, TTSEngine::Chatterbox | TTSEngine::Qwen3TTS) {
+        let tts = Arc::clone(&state.tts);
+        let result = tokio::task::spawn_blocking(move || {
+            if !tts.is_initialized() {
+                tts.start()?;
+                tts.init_model()?;
+            }
+            tts.warmup()
+        })
+        .await;
+
+        return match result {
+            Ok(Ok(())) => CommandResponse::Success { content: true },
+            Ok(Err(e)) => {
+                println!("[TTS] Warmup failed: {}", e);
+                Err(e).into()
+            }
+            Err(e) => CommandResponse::Fatal {
+                message: format!("Task error: {}", e),
+            },
+        };
+    }
+
     // Initialize the Echo model (downloads on first use)
     match state.echo.initialize().await {
         Ok(_) => {
             println!("[Echo] Warmup: model initialized");
-            Ok(true)
+            CommandResponse::Success { content: true }
         }
         Err(e) => {
             println!("[Echo] Warmup failed: {}", e);
-            Ok(false)
+            Err(e).into()
         }
     }
 }
 
+/// Synthesize a chapter and export it as a tagged audiobook file.
+///
+/// Produces a properly tagged file (ID3v2 for MP3, Vorbis comments for OGG,
+/// plain WAV otherwise) so the reader can build a per-chapter audiobook.
+#[tauri::command]
+pub async fn export_chapter_audio(
+    chapter_index: usize,
+    format: AudioFormat,
+    metadata: AudioMetadata,
+    output_path: String,
+    state: State<'_, AppState>,
+) -> CommandResponse<String> {
+    // Resolve the chapter text from the currently loaded book.
+    let text = {
+        let current = match state.current_book.lock() {
+            Ok(current) => current,
+            Err(e) => {
+                return CommandResponse::Fatal {
+                    message: e.to_string(),
+                }
+            }
+        };
+        let Some(book) = current.as_ref() else {
+            // The user just needs to open a book first; no code change
+            // needed for a retry to succeed.
+            return CommandResponse::Failure {
+                message: "No book loaded".to_string(),
+            };
+        };
+        let Some(chapter) = book.chapters.get(chapter_index) else {
+            // Same shape as "No book loaded" above: the user just needs to
+            // pick a valid chapter index, so this is recoverable too.
+            return CommandResponse::Failure {
+                message: format!("Chapter {} not found", chapter_index),
+            };
+        };
+        chapter.content.clone()
+    };
+
+    // Synthesize through the same Echo-1B engine `tts_stream_text` uses,
+    // rather than the removed placeholder generator -- downloads the model
+    // on first use just like warmup/streaming do. Echo's `generate` has no
+    // speed parameter (the same pre-existing limitation `tts_stream_text`'s
+    // streaming path already lives with), so `audio_speed` isn't applied here.
+    if !state.echo.is_initialized() {
+        if let Err(e) = state.echo.initialize().await {
+            return CommandResponse::Fatal {
+                message: format!("Echo init failed: {}", e),
+            };
+        }
+    }
+
+    let result = match state.echo.generate(&text, 0, 0.7).await {
+        Ok(result) => result,
+        Err(e) => {
+            return CommandResponse::Failure {
+                message: format!("Generation error: {}", e),
+            }
+        }
+    };
+
+    let bytes = tokio::task::spawn_blocking(move || {
+        result.encode(format, &metadata).map_err(|e| e.to_string())
+    })
+    .await;
+
+    let bytes = match bytes {
+        Ok(Ok(bytes)) => bytes,
+        Ok(Err(message)) => return CommandResponse::Failure { message },
+        Err(e) => {
+            return CommandResponse::Fatal {
+                message: format!("Task error: {}", e),
+            }
+        }
+    };
+
+    match fs::write(&output_path, bytes) {
+        Ok(()) => CommandResponse::Success {
+            content: output_path,
+        },
+        // Disk-full/permission hiccups can be transient (retry after the
+        // user frees space or picks a writable path).
+        Err(e) => CommandResponse::Failure {
+            message: format!("Failed to write file: {}", e),
+        },
+    }
+}
+
+/// Synthesize the whole loaded book through Echo-1B and roll it into one
+/// segment file per chapter via `SegmentWriter`, emitting a
+/// `segment-export-progress` event as each file finishes so the frontend can
+/// build a chapter index and show progress as it goes, rather than only
+/// learning the result once the entire book has been exported.
+#[tauri::command]
+pub async fn export_book_segments(
+    output_dir: String,
+    base_name: String,
+    format: AudioFormat,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResponse<Vec<SegmentInfo>> {
+    let chapters = {
+        let current = match state.current_book.lock() {
+            Ok(current) => current,
+            Err(e) => {
+                return CommandResponse::Fatal {
+                    message: e.to_string(),
+                }
+            }
+        };
+        let Some(book) = current.as_ref() else {
+            return CommandResponse::Failure {
+                message: "No book loaded".to_string(),
+            };
+        };
+        book.chapters.clone()
+    };
+
+    if chapters.is_empty() {
+        return CommandResponse::Failure {
+            message: "Book has no chapters".to_string(),
+        };
+    }
+
+    if !state.echo.is_initialized() {
+        if let Err(e) = state.echo.initialize().await {
+            return CommandResponse::Fatal {
+                message: format!("Echo init failed: {}", e),
+            };
+        }
+    }
+
+    let sample_rate = state.echo.sample_rate().await;
+    let segments = Arc::new(Mutex::new(Vec::new()));
+    let segments_for_callback = Arc::clone(&segments);
+    let mut writer = SegmentWriter::new(
+        output_dir,
+        base_name,
+        format,
+        sample_rate,
+        Arc::new(move |info: SegmentInfo| {
+            let _ = app.emit("segment-export-progress", info.clone());
+            if let Ok(mut segments) = segments_for_callback.lock() {
+                segments.push(info);
+            }
+        }),
+    );
+
+    for chapter in &chapters {
+        let result = match state.echo.generate(&chapter.content, 0, 0.7).await {
+            Ok(result) => result,
+            Err(e) => {
+                return CommandResponse::Failure {
+                    message: format!("Generation error on chapter {}: {}", chapter.index, e),
+                }
+            }
+        };
+
+        if let Err(e) = writer.feed(&result, &chapter.title) {
+            return CommandResponse::Fatal {
+                message: format!("Segment write error: {}", e),
+            };
+        }
+        if let Err(e) = writer.mark_chapter_boundary() {
+            return CommandResponse::Fatal {
+                message: format!("Segment write error: {}", e),
+            };
+        }
+    }
+
+    if let Err(e) = writer.finish() {
+        return CommandResponse::Fatal {
+            message: format!("Segment write error: {}", e),
+        };
+    }
+
+    match Arc::try_unwrap(segments) {
+        Ok(segments) => CommandResponse::Success {
+            content: segments.into_inner().unwrap_or_default(),
+        },
+        Err(segments) => CommandResponse::Success {
+            content: segments.lock().map(|s| s.clone()).unwrap_or_default(),
+        },
+    }
+}
+
 // ============================================================================
 // Model Download Commands
 // ============================================================================
@@ -456,40 +1261,49 @@ pub struct DownloadProgress {
     pub status: String,
 }
 
-/// Check if models are downloaded and ready
+/// Check if models are downloaded and ready.
+///
+/// Diffs the model repo's real file manifest against the local HuggingFace
+/// cache snapshot, so `missing_files`/`download_size_bytes` reflect the
+/// actual remaining work rather than a single guessed-at entry. Only the
+/// manifest is fetched here (no file bytes), so this stays cheap enough to
+/// call on every status check.
 #[tauri::command]
 pub fn check_model_status(state: State<'_, AppState>) -> ModelStatus {
     let _engine = state.get_engine().unwrap_or_default();
+    let cache_dir = echo_model_cache_dir();
 
-    // Only Echo engine is currently supported
-    // Check if sesame/csm-1b model exists in HuggingFace cache
-    let cache_dir = dirs::home_dir()
-        .map(|p| {
-            p.join(".cache")
-                .join("huggingface")
-                .join("hub")
-                .join("models--sesame--csm-1b")
-        })
-        .unwrap_or_default();
-
-    // Check if model directory exists AND has snapshots (actual model files)
-    let snapshots_dir = cache_dir.join("snapshots");
-    let is_ready = snapshots_dir.exists() && snapshots_dir.is_dir() && {
-        // Check if snapshots directory has any content
-        std::fs::read_dir(&snapshots_dir)
-            .map(|mut entries| entries.next().is_some())
-            .unwrap_or(false)
+    let manifest = match fetch_echo_model_manifest() {
+        Ok(manifest) => manifest,
+        // Can't reach HuggingFace right now -- fall back to the coarse
+        // snapshot-presence check rather than failing the status check.
+        Err(e) => {
+            eprintln!("[Echo] Failed to fetch model manifest: {}", e);
+            let snapshots_dir = cache_dir.join("snapshots");
+            let is_ready = snapshots_dir.is_dir()
+                && std::fs::read_dir(&snapshots_dir)
+                    .map(|mut entries| entries.next().is_some())
+                    .unwrap_or(false);
+            return ModelStatus {
+                is_ready,
+                is_downloading: false,
+                missing_files: if is_ready {
+                    vec![]
+                } else {
+                    vec!["sesame/csm-1b".to_string()]
+                },
+                download_size_bytes: if is_ready { 0 } else { 4_000_000_000 },
+                model_dir: cache_dir.to_string_lossy().to_string(),
+            };
+        }
     };
 
+    let missing = echo_model_missing_files(&manifest);
     ModelStatus {
-        is_ready,
+        is_ready: missing.is_empty(),
         is_downloading: false,
-        missing_files: if is_ready {
-            vec![]
-        } else {
-            vec!["sesame/csm-1b".to_string()]
-        },
-        download_size_bytes: if is_ready { 0 } else { 4_000_000_000 },
+        missing_files: missing.iter().map(|f| f.rfilename.clone()).collect(),
+        download_size_bytes: echo_model_download_size(&missing),
         model_dir: cache_dir.to_string_lossy().to_string(),
     }
 }
@@ -498,40 +1312,92 @@ pub fn check_model_status(state: State<'_, AppState>) -> ModelStatus {
 /// Triggers Echo model initialization which downloads from HuggingFace on first call
 #[tauri::command]
 pub async fn download_model(
+    model_source: Option<Vec<String>>,
     app: tauri::AppHandle,
     state: State<'_, AppState>,
-) -> Result<(), String> {
+) -> CommandResponse<()> {
+    // Remember any configured mirrors so downloads prefer them over the default.
+    if let Some(mirrors) = model_source {
+        let mirrors = mirrors
+            .into_iter()
+            .filter(|m| !m.trim().is_empty())
+            .collect::<Vec<_>>();
+        match state.model_source.lock() {
+            Ok(mut guard) => {
+                *guard = if mirrors.is_empty() {
+                    None
+                } else {
+                    Some(mirrors)
+                }
+            }
+            Err(e) => return Err(EchoError::InitError(e.to_string())).into(),
+        }
+    }
+
     let echo = Arc::clone(&state.echo);
+    let mirrors = state
+        .model_source
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .unwrap_or_default();
 
-    // Emit starting status
-    let _ = app.emit(
-        "model-download-progress",
-        DownloadProgress {
-            file_name: "sesame/csm-1b".to_string(),
-            bytes_downloaded: 0,
-            total_bytes: Some(4_000_000_000), // ~4GB estimate
-            current_file: 1,
-            total_files: 1,
-            status: "downloading".to_string(),
-        },
-    );
+    // Pre-fetch the real manifest and stream each missing file ourselves, so
+    // the frontend gets true per-file progress instead of one guessed total.
+    // `echo.initialize()` below still does its own HuggingFace fetch
+    // afterward; when it resolves to the same snapshot we just populated,
+    // that's a cache hit, otherwise it simply re-downloads what it needs.
+    let app_for_progress = app.clone();
+    let download_result = tokio::task::spawn_blocking(move || {
+        let manifest = fetch_echo_model_manifest()?;
+        let missing = echo_model_missing_files(&manifest);
+        download_echo_model_files(&missing, &mirrors, &|progress| {
+            let _ = app_for_progress.emit(
+                "model-download-progress",
+                DownloadProgress {
+                    file_name: progress.file_name,
+                    bytes_downloaded: progress.bytes_downloaded,
+                    total_bytes: progress.total_bytes,
+                    current_file: progress.current_file,
+                    total_files: progress.total_files,
+                    status: progress.status,
+                },
+            );
+        })
+    })
+    .await;
+
+    match download_result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            eprintln!(
+                "[Echo] Manifest pre-fetch failed, falling back to echo's own download: {}",
+                e
+            );
+        }
+        Err(e) => {
+            return CommandResponse::Fatal {
+                message: format!("Task error: {}", e),
+            }
+        }
+    }
 
     // Initialize Echo - this triggers the HuggingFace download
-    match echo.initialize().await {
+    let result = echo.initialize().await;
+    match &result {
         Ok(_) => {
             println!("[Echo] Model downloaded and initialized successfully");
             let _ = app.emit(
                 "model-download-progress",
                 DownloadProgress {
                     file_name: "sesame/csm-1b".to_string(),
-                    bytes_downloaded: 4_000_000_000,
-                    total_bytes: Some(4_000_000_000),
-                    current_file: 1,
-                    total_files: 1,
+                    bytes_downloaded: 0,
+                    total_bytes: None,
+                    current_file: 0,
+                    total_files: 0,
                     status: "complete".to_string(),
                 },
             );
-            Ok(())
         }
         Err(e) => {
             eprintln!("[Echo] Model download failed: {}", e);
@@ -541,14 +1407,15 @@ pub async fn download_model(
                     file_name: "sesame/csm-1b".to_string(),
                     bytes_downloaded: 0,
                     total_bytes: None,
-                    current_file: 1,
-                    total_files: 1,
+                    current_file: 0,
+                    total_files: 0,
                     status: format!("error: {}", e),
                 },
             );
-            Err(format!("Model download failed: {}", e))
         }
     }
+
+    result.into()
 }
 
 /// Download a specific voice (not applicable for Chatterbox)
@@ -557,8 +1424,8 @@ pub async fn download_voice(
     _voice_id: String,
     app: tauri::AppHandle,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    download_model(app, state).await
+) -> CommandResponse<()> {
+    download_model(None, app, state).await
 }
 
 /// Get the model directory path
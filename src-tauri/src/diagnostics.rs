@@ -0,0 +1,149 @@
+//! Structured, session-correlated tracing for the TTS pipeline.
+//!
+//! Ad-hoc `println!`/`eprintln!` calls can't be filtered, correlated to a
+//! session, or shown in-app. This installs a `tracing` subscriber instead: a
+//! reloadable `EnvFilter` (changed at runtime via `set_log_level`) gates what
+//! gets recorded, and a custom layer forwards every recorded event to the
+//! frontend as a `diagnostics-event`, tagged with the `session_id` of its
+//! innermost enclosing span (if any), so a debug panel can show live
+//! generation timings and errors as they happen.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Emitter};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
+
+/// One structured log event, shaped for the frontend's debug panel.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsEvent {
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    /// The `session_id` field of the innermost enclosing
+    /// `#[tracing::instrument]` span, if the event occurred inside one.
+    pub session_id: Option<String>,
+    /// Any other structured fields recorded on the event, stringified.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// The live reload handle for the subscriber's `EnvFilter`, set once by
+/// `init` and used by `set_log_level` to change verbosity at runtime.
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Install the global tracing subscriber: a reloadable `EnvFilter` plus a
+/// layer forwarding events to the frontend. Call once, during app setup.
+pub fn init(app: AppHandle) {
+    let (filter, handle) = reload::Layer::new(EnvFilter::new("info"));
+    let _ = FILTER_HANDLE.set(handle);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(ForwardingLayer { app })
+        .init();
+}
+
+/// Change the global log level at runtime. Accepts a bare level
+/// (`"trace"`/`"debug"`/`"info"`/`"warn"`/`"error"`) or a full `EnvFilter`
+/// directive string (e.g. `"warn,kokoro_reader_lib::tts=debug"`).
+pub fn set_log_level(directive: &str) -> Result<(), String> {
+    let new_filter = EnvFilter::try_new(directive).map_err(|e| e.to_string())?;
+    FILTER_HANDLE
+        .get()
+        .ok_or_else(|| "tracing not initialized".to_string())?
+        .reload(new_filter)
+        .map_err(|e| e.to_string())
+}
+
+/// The `session_id` field recorded when a span carrying one was created,
+/// stashed in the span's extensions so later events within it (and its
+/// children) can be tagged without repeating the field on every call.
+struct SessionId(String);
+
+struct ForwardingLayer {
+    app: AppHandle,
+}
+
+impl<S> Layer<S> for ForwardingLayer
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_new_span(
+        &self,
+        attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        ctx: Context<'_, S>,
+    ) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(session_id) = visitor.fields.get("session_id").cloned() {
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(SessionId(session_id));
+            }
+        }
+    }
+
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let session_id = ctx.event_scope(event).and_then(|mut scope| {
+            scope.find_map(|span| span.extensions().get::<SessionId>().map(|id| id.0.clone()))
+        });
+
+        let _ = self.app.emit(
+            "diagnostics-event",
+            DiagnosticsEvent {
+                level: event.metadata().level().to_string(),
+                target: event.metadata().target().to_string(),
+                message: visitor.message.unwrap_or_default(),
+                session_id,
+                fields: visitor.fields,
+            },
+        );
+    }
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: BTreeMap<String, String>,
+}
+
+impl FieldVisitor {
+    fn record(&mut self, field: &tracing::field::Field, formatted: String) {
+        if field.name() == "message" {
+            self.message = Some(formatted);
+        } else {
+            self.fields.insert(field.name().to_string(), formatted);
+        }
+    }
+}
+
+impl tracing::field::Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.record(field, format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+        self.record(field, value.to_string());
+    }
+}
@@ -0,0 +1,5 @@
+//! EPUB/text book parsing.
+
+mod parser;
+
+pub use parser::{Book, BookMetadata, Chapter, EpubParser, ParseError, TocEntry, Word};
@@ -1,8 +1,10 @@
 //! EPUB and text file parser
 
-use epub::doc::EpubDoc;
+use epub::doc::{EpubDoc, NavPoint};
 use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
+use std::io::{Read, Seek};
 use std::path::Path;
 use thiserror::Error;
 
@@ -44,12 +46,29 @@ pub struct Word {
     pub end_offset: usize,
 }
 
+/// One entry of the book's navigation document (EPUB2 `toc.ncx` or EPUB3
+/// `nav.xhtml`), forming a tree that mirrors the author's actual table of
+/// contents rather than the flat spine order.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TocEntry {
+    pub title: String,
+    /// Spine index the entry's target resolves to, if it could be matched.
+    pub spine_index: Option<usize>,
+    /// `#fragment` from the entry's target href, for jumping to a specific
+    /// heading within a spine item rather than the item's start.
+    pub fragment: Option<String>,
+    pub children: Vec<TocEntry>,
+}
+
 /// Parsed book
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Book {
     pub metadata: BookMetadata,
     pub chapters: Vec<Chapter>,
     pub total_words: usize,
+    /// Hierarchical table of contents from the EPUB's navigation document.
+    /// Empty for plain text files, which have no such document.
+    pub toc: Vec<TocEntry>,
 }
 
 /// EPUB and text file parser
@@ -88,6 +107,14 @@ impl EpubParser {
         let language = doc.mdata("language").map(|m| m.value.clone());
         let description = doc.mdata("description").map(|m| m.value.clone());
 
+        // Build the navigation tree (toc.ncx / nav.xhtml) and a spine-index
+        // -> title lookup from it, so real chapter titles take priority over
+        // the `<h1-3>` regex guess below.
+        let spine_paths = Self::spine_resource_paths(&doc);
+        let toc = Self::build_toc(&doc.toc, &spine_paths);
+        let mut nav_titles = HashMap::new();
+        Self::collect_nav_titles(&toc, &mut nav_titles);
+
         // Parse chapters
         let mut chapters = Vec::new();
         let mut total_words = 0;
@@ -109,8 +136,13 @@ impl EpubParser {
                 let words = Self::extract_words(&plain_text);
                 total_words += words.len();
 
-                // Try to extract chapter title from content
-                let chapter_title = Self::extract_title(&content)
+                // Prefer the navigation document's label for this spine
+                // item; fall back to the old heading-regex guess, then a
+                // numbered placeholder.
+                let chapter_title = nav_titles
+                    .get(&index)
+                    .cloned()
+                    .or_else(|| Self::extract_title(&content))
                     .unwrap_or_else(|| format!("Chapter {}", chapters.len() + 1));
 
                 chapters.push(Chapter {
@@ -132,9 +164,80 @@ impl EpubParser {
             },
             chapters,
             total_words,
+            toc,
         })
     }
 
+    /// Spine index paired with each spine item's resource path, normalized
+    /// for comparison against navigation document hrefs (which are relative
+    /// to the same OPF base directory).
+    fn spine_resource_paths<R: Read + Seek>(doc: &EpubDoc<R>) -> Vec<(usize, String)> {
+        doc.spine
+            .iter()
+            .enumerate()
+            .filter_map(|(index, id)| {
+                doc.resources
+                    .get(id)
+                    .map(|(path, _mime)| (index, Self::normalize_href(&path.to_string_lossy())))
+            })
+            .collect()
+    }
+
+    /// Recursively convert the navigation document's parsed tree into
+    /// `TocEntry`s, resolving each target href to a spine index.
+    fn build_toc(nav_points: &[NavPoint], spine_paths: &[(usize, String)]) -> Vec<TocEntry> {
+        nav_points
+            .iter()
+            .map(|point| {
+                let href = point.content.to_string_lossy().into_owned();
+                let (path, fragment) = match href.split_once('#') {
+                    Some((path, fragment)) => (path.to_string(), Some(fragment.to_string())),
+                    None => (href, None),
+                };
+
+                TocEntry {
+                    title: point.label.trim().to_string(),
+                    spine_index: Self::resolve_spine_index(&path, spine_paths),
+                    fragment,
+                    children: Self::build_toc(&point.children, spine_paths),
+                }
+            })
+            .collect()
+    }
+
+    /// Match a navigation href against the spine, first by exact normalized
+    /// path, then by file name alone (navigation documents and the OPF
+    /// manifest occasionally use slightly different relative prefixes for
+    /// the same file).
+    fn resolve_spine_index(path: &str, spine_paths: &[(usize, String)]) -> Option<usize> {
+        let normalized = Self::normalize_href(path);
+        spine_paths
+            .iter()
+            .find(|(_, p)| *p == normalized)
+            .or_else(|| {
+                let file_name = normalized.rsplit('/').next().unwrap_or(&normalized);
+                spine_paths
+                    .iter()
+                    .find(|(_, p)| p.rsplit('/').next() == Some(file_name))
+            })
+            .map(|(index, _)| *index)
+    }
+
+    fn normalize_href(href: &str) -> String {
+        href.trim_start_matches("./").replace('\\', "/")
+    }
+
+    /// Flatten the toc tree into a spine-index -> title map, keeping the
+    /// first (outermost, reading-order) title seen for each spine index.
+    fn collect_nav_titles(entries: &[TocEntry], titles: &mut HashMap<usize, String>) {
+        for entry in entries {
+            if let Some(index) = entry.spine_index {
+                titles.entry(index).or_insert_with(|| entry.title.clone());
+            }
+            Self::collect_nav_titles(&entry.children, titles);
+        }
+    }
+
     /// Parse a plain text file
     fn parse_text(path: &Path) -> Result<Book, ParseError> {
         let content = fs::read_to_string(path).map_err(|e| ParseError::FileError(e.to_string()))?;
@@ -185,6 +288,7 @@ impl EpubParser {
             },
             chapters,
             total_words,
+            toc: Vec::new(),
         })
     }
 
@@ -3,7 +3,9 @@
 //! An ebook reader with AI-powered text-to-speech using Kokoro-82M TTS.
 
 mod ai;
+mod command_result;
 mod commands;
+mod diagnostics;
 mod epub;
 mod tts;
 
@@ -20,6 +22,8 @@ pub fn run() {
         .plugin(tauri_plugin_deep_link::init())
         .manage(AppState::new())
         .setup(|app| {
+            diagnostics::init(app.handle().clone());
+
             // Handle deep links for auth callback
             // Expected format: textclarifier://auth?token=xxx&refresh_token=yyy
             let handle = app.handle().clone();
@@ -62,9 +66,13 @@ pub fn run() {
             commands::get_current_book,
             commands::get_chapter,
             commands::speak,
+            commands::set_chatterbox_reference_voice,
+            commands::clear_chatterbox_reference_voice,
             // Chunked / queued TTS
             commands::tts_start_session,
             commands::tts_enqueue_chunk,
+            commands::tts_stream_text,
+            commands::tts_stream_chapter,
             commands::tts_stop,
             commands::tts_pause,
             commands::tts_resume,
@@ -72,16 +80,28 @@ pub fn run() {
             commands::pause_speaking,
             commands::resume_speaking,
             commands::set_speed,
+            commands::set_spatial_position,
+            commands::tts_start_recording,
+            commands::tts_stop_recording,
+            commands::list_audio_devices,
+            commands::set_audio_backend,
             commands::is_playing,
             commands::is_paused,
+            commands::tts_seek,
+            commands::tts_position,
+            commands::tts_seek_stream,
             commands::get_voices,
             commands::set_tts_engine,
             commands::get_tts_engine,
+            commands::export_chapter_audio,
+            commands::export_book_segments,
             // Model download commands
             commands::check_model_status,
             commands::download_model,
             commands::download_voice,
             commands::get_model_dir,
+            // Diagnostics
+            commands::set_log_level,
             // AI Commands
             ai::open_auth_window,
             ai::explain_text,
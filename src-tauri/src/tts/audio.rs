@@ -1,11 +1,13 @@
-//! Audio playback controller using rodio
+//! One-shot blocking audio playback using rodio.
 //!
-//! Note: rodio's OutputStream is not Send, so we use thread_local and lazy initialization
+//! Real-time, queue-aware playback (pause/resume/seek, HRTF spatialization,
+//! position reporting) lives in `PlaybackManager`/`audio_backend`, driven by
+//! its own long-lived audio thread. This module is just the minimal blocking
+//! fallback `play_wav_blocking` uses for callers with no queue/session to
+//! enqueue through that for (the legacy `speak` command).
 
-use rodio::{OutputStream, Sink, Source};
+use rodio::{OutputStream, Sink, Source as _};
 use std::io::Cursor;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -18,76 +20,17 @@ pub enum AudioError {
     PlaybackError(String),
 }
 
-/// Audio player that manages playback on the main thread
-/// Since OutputStream is not Send, we use a simpler approach with Option types
-pub struct AudioPlayer {
-    speed: f32,
-    is_playing: Arc<AtomicBool>,
+/// Decode and play `wav_data` to completion, blocking the calling thread
+/// until playback finishes. For one-shot callers (the legacy `speak`
+/// command) that have no queue/session to enqueue through `PlaybackManager`
+/// for.
+pub fn play_wav_blocking(wav_data: Vec<u8>) -> Result<(), AudioError> {
+    let (_stream, handle) =
+        OutputStream::try_default().map_err(|e| AudioError::StreamError(e.to_string()))?;
+    let sink = Sink::try_new(&handle).map_err(|e| AudioError::StreamError(e.to_string()))?;
+    let source = rodio::Decoder::new(Cursor::new(wav_data))
+        .map_err(|e| AudioError::DecodeError(e.to_string()))?;
+    sink.append(source);
+    sink.sleep_until_end();
+    Ok(())
 }
-
-impl AudioPlayer {
-    /// Create a new audio player
-    pub fn new() -> Self {
-        Self {
-            speed: 1.0,
-            is_playing: Arc::new(AtomicBool::new(false)),
-        }
-    }
-
-    /// Play WAV audio data (blocking on the calling thread)
-    pub fn play_wav_blocking(&self, wav_data: Vec<u8>) -> Result<(), AudioError> {
-        // Create stream fresh each time (works around the Send issue)
-        let (_stream, stream_handle) = OutputStream::try_default()
-            .map_err(|e| AudioError::StreamError(e.to_string()))?;
-
-        let sink = Sink::try_new(&stream_handle)
-            .map_err(|e| AudioError::StreamError(e.to_string()))?;
-
-        // Decode WAV
-        let cursor = Cursor::new(wav_data);
-        let source = rodio::Decoder::new(cursor)
-            .map_err(|e| AudioError::DecodeError(e.to_string()))?;
-
-        // Apply speed
-        let source = source.speed(self.speed);
-
-        sink.append(source);
-        self.is_playing.store(true, Ordering::SeqCst);
-        
-        // Wait for playback to finish
-        sink.sleep_until_end();
-        self.is_playing.store(false, Ordering::SeqCst);
-
-        Ok(())
-    }
-
-    /// Set playback speed (0.5 - 2.0)
-    pub fn set_speed(&mut self, speed: f32) {
-        self.speed = speed.clamp(0.5, 2.0);
-    }
-
-    /// Get current speed
-    pub fn get_speed(&self) -> f32 {
-        self.speed
-    }
-
-    /// Check if audio is currently playing
-    pub fn is_playing(&self) -> bool {
-        self.is_playing.load(Ordering::SeqCst)
-    }
-
-    /// Stop playback (sets flag, actual stop happens in play loop)
-    pub fn request_stop(&self) {
-        self.is_playing.store(false, Ordering::SeqCst);
-    }
-}
-
-impl Default for AudioPlayer {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-// AudioPlayer is now Send + Sync since we removed OutputStream
-unsafe impl Send for AudioPlayer {}
-unsafe impl Sync for AudioPlayer {}
@@ -0,0 +1,389 @@
+//! Pluggable audio output backends.
+//!
+//! The audio thread talks to an [`AudioBackend`] rather than a fixed rodio
+//! `Sink`, so output can be routed to a chosen device, written as raw PCM to a
+//! file/stdout, or piped into an external player. Backends are looked up by
+//! name in [`BACKENDS`], mirroring librespot's sink registry.
+//!
+//! Backends are constructed and used entirely on the audio thread, so they do
+//! not need to be `Send` (rodio's `OutputStream` is not).
+
+use crate::tts::resample::ResampledSource;
+use crate::tts::spatial::{HrirSet, SpatialConfig};
+use crate::tts::streaming_source::StreamingSource;
+use rodio::{Decoder, OutputStream, Sink, Source};
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::Arc;
+
+/// A decoded, speed-adjusted output sink.
+pub trait AudioBackend {
+    /// Start a fresh output for a new session, discarding any prior state.
+    fn reset(&mut self) -> Result<(), String>;
+    /// Append a WAV chunk, applying the given playback speed.
+    fn append_wav(&mut self, wav: Vec<u8>, speed: f32) -> Result<(), String>;
+    /// Append a progressively-arriving PCM source (e.g. Python streaming
+    /// mode), applying the given playback speed. Backends that can't consume
+    /// a `Source` directly (file/pipe targets) drain it eagerly instead.
+    fn append_source(&mut self, source: StreamingSource, speed: f32) -> Result<(), String>;
+    fn pause(&mut self);
+    fn resume(&mut self);
+    fn stop(&mut self);
+    fn is_paused(&self) -> bool;
+    /// Number of queued-but-unplayed chunks. Streaming/blocking backends that
+    /// cannot report this return 0.
+    fn queued_len(&self) -> usize;
+    /// Whether all queued audio has finished.
+    fn is_empty(&self) -> bool;
+    /// Enable (or, if `None`, disable) HRTF binaural rendering for chunks
+    /// appended from now on. Backends that only ever write flat interleaved
+    /// PCM (file/pipe/subprocess targets) have no stereo image to place, so
+    /// the default is a no-op.
+    fn set_spatial(&mut self, _config: Option<SpatialConfig>) {}
+}
+
+/// Constructor signature shared by every registered backend.
+pub type BackendBuilder = fn(Option<String>) -> Result<Box<dyn AudioBackend>, String>;
+
+/// Named backend registry. The first entry is the default.
+pub const BACKENDS: &[(&str, BackendBuilder)] = &[
+    ("rodio", RodioBackend::open),
+    ("pipe", PipeBackend::open),
+    ("subprocess", SubprocessBackend::open),
+];
+
+/// Look up a backend builder by name, falling back to the default (`rodio`).
+pub fn builder_for(name: &str) -> BackendBuilder {
+    BACKENDS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, b)| *b)
+        .unwrap_or(BACKENDS[0].1)
+}
+
+/// Enumerate output device names reported by cpal.
+pub fn list_devices() -> Vec<String> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+    let host = cpal::default_host();
+    host.output_devices()
+        .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Default rodio backend: plays through a (possibly named) output device.
+pub struct RodioBackend {
+    _stream: OutputStream,
+    handle: rodio::OutputStreamHandle,
+    sink: Option<Sink>,
+    /// The device's native sample rate, used to resample streamed sources to
+    /// device rate ourselves rather than leaving it to rodio's internal
+    /// resampler. `None` if cpal couldn't report a config for the device.
+    device_sample_rate: Option<u32>,
+    /// HRTF placement to apply to chunks appended from now on, if any.
+    spatial: Option<SpatialConfig>,
+    /// The `HrirSet` last loaded for `spatial`, keyed by the directory and
+    /// sample rate it was built for, so switching position doesn't reload
+    /// the impulse responses from disk every chunk.
+    hrir_cache: Option<(PathBuf, u32, Arc<HrirSet>)>,
+}
+
+impl RodioBackend {
+    pub fn open(device: Option<String>) -> Result<Box<dyn AudioBackend>, String> {
+        let (stream, handle) = match &device {
+            Some(name) => Self::open_named(name)?,
+            None => OutputStream::try_default().map_err(|e| e.to_string())?,
+        };
+        Ok(Box::new(Self {
+            _stream: stream,
+            handle,
+            sink: None,
+            device_sample_rate: query_output_sample_rate(device.as_deref()),
+            spatial: None,
+            hrir_cache: None,
+        }))
+    }
+
+    fn open_named(name: &str) -> Result<(OutputStream, rodio::OutputStreamHandle), String> {
+        use cpal::traits::{DeviceTrait, HostTrait};
+        let host = cpal::default_host();
+        let device = host
+            .output_devices()
+            .map_err(|e| e.to_string())?
+            .find(|d| d.name().map(|n| n == name).unwrap_or(false))
+            .ok_or_else(|| format!("Output device not found: {}", name))?;
+        OutputStream::try_from_device(&device).map_err(|e| e.to_string())
+    }
+}
+
+/// Query the native sample rate of the named output device (or the default
+/// device, if `None`), for deciding whether a streamed source needs
+/// resampling to match it.
+fn query_output_sample_rate(device: Option<&str>) -> Option<u32> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+    let host = cpal::default_host();
+    let device = match device {
+        Some(name) => host
+            .output_devices()
+            .ok()?
+            .find(|d| d.name().map(|n| n == name).unwrap_or(false))?,
+        None => host.default_output_device()?,
+    };
+    device
+        .default_output_config()
+        .ok()
+        .map(|c| c.sample_rate().0)
+}
+
+impl AudioBackend for RodioBackend {
+    fn reset(&mut self) -> Result<(), String> {
+        if let Some(sink) = self.sink.take() {
+            sink.stop();
+        }
+        self.sink = Some(Sink::try_new(&self.handle).map_err(|e| e.to_string())?);
+        Ok(())
+    }
+
+    fn append_wav(&mut self, wav: Vec<u8>, speed: f32) -> Result<(), String> {
+        let sink = self.sink.as_ref().ok_or("sink not initialized")?;
+        let source = Decoder::new(Cursor::new(wav)).map_err(|e| e.to_string())?;
+        let speed = speed.clamp(0.5, 2.0);
+        match resolve_spatial(&self.spatial, source.sample_rate(), &mut self.hrir_cache) {
+            Some((config, hrir)) => {
+                let mono = source.convert_samples::<f32>();
+                sink.append(
+                    hrir.render(mono, config.azimuth, config.elevation)
+                        .speed(speed),
+                );
+            }
+            None => sink.append(source.speed(speed)),
+        }
+        Ok(())
+    }
+
+    fn append_source(&mut self, source: StreamingSource, speed: f32) -> Result<(), String> {
+        let sink = self.sink.as_ref().ok_or("sink not initialized")?;
+        let speed = speed.clamp(0.5, 2.0);
+        // Appended directly as a Source (no eager decode): the Sink starts
+        // playing the first buffered block while the rest streams in.
+        match resolve_spatial(&self.spatial, source.sample_rate(), &mut self.hrir_cache) {
+            Some((config, hrir)) => {
+                sink.append(
+                    hrir.render(source, config.azimuth, config.elevation)
+                        .speed(speed),
+                );
+            }
+            None => match self.device_sample_rate {
+                // Resample to device rate ourselves with a high-quality sinc
+                // interpolator, rather than letting rodio's internal resampler
+                // (lower quality, designed for convenience not fidelity) do it.
+                Some(target) if target != source.sample_rate() => {
+                    sink.append(ResampledSource::new(source, target).speed(speed));
+                }
+                _ => {
+                    sink.append(source.speed(speed));
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn pause(&mut self) {
+        if let Some(sink) = self.sink.as_ref() {
+            sink.pause();
+        }
+    }
+
+    fn resume(&mut self) {
+        if let Some(sink) = self.sink.as_ref() {
+            sink.play();
+        }
+    }
+
+    fn stop(&mut self) {
+        if let Some(sink) = self.sink.take() {
+            sink.stop();
+        }
+    }
+
+    fn is_paused(&self) -> bool {
+        self.sink.as_ref().map(|s| s.is_paused()).unwrap_or(false)
+    }
+
+    fn queued_len(&self) -> usize {
+        self.sink.as_ref().map(|s| s.len()).unwrap_or(0)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.sink.as_ref().map(|s| s.empty()).unwrap_or(true)
+    }
+
+    /// Enable HRTF binaural rendering at `config`'s azimuth/elevation (or
+    /// bypass the stage when `None`). This is the live path `set_spatial_position`
+    /// drives via `PlaybackManager` -- the now-removed `AudioPlayer` used to
+    /// carry a second copy of this same HRIR-convolution stage for its
+    /// single-clip playback, which never ran since nothing constructed it.
+    fn set_spatial(&mut self, config: Option<SpatialConfig>) {
+        self.spatial = config;
+    }
+}
+
+/// Resolve `config` into a loaded (and cached) `HrirSet` matched to
+/// `sample_rate`, reloading only when the HRIR directory or rate changes
+/// from what's cached.
+fn resolve_spatial(
+    config: &Option<SpatialConfig>,
+    sample_rate: u32,
+    cache: &mut Option<(PathBuf, u32, Arc<HrirSet>)>,
+) -> Option<(SpatialConfig, Arc<HrirSet>)> {
+    let config = config.clone()?;
+
+    if let Some((path, rate, set)) = cache.as_ref() {
+        if *path == config.hrir_path && *rate == sample_rate {
+            return Some((config, Arc::clone(set)));
+        }
+    }
+
+    match HrirSet::load(&config.hrir_path, sample_rate) {
+        Ok(set) => {
+            let set = Arc::new(set);
+            *cache = Some((config.hrir_path.clone(), sample_rate, Arc::clone(&set)));
+            Some((config, set))
+        }
+        Err(e) => {
+            eprintln!("[RodioBackend] Failed to load HRIR set: {}", e);
+            None
+        }
+    }
+}
+
+/// Decode a WAV chunk to interleaved little-endian `i16` PCM.
+fn wav_to_pcm_le(wav: Vec<u8>) -> Result<Vec<u8>, String> {
+    let decoder = Decoder::new(Cursor::new(wav)).map_err(|e| e.to_string())?;
+    let mut out = Vec::new();
+    for sample in decoder.convert_samples::<i16>() {
+        out.extend_from_slice(&sample.to_le_bytes());
+    }
+    Ok(out)
+}
+
+/// Drain a streaming PCM source into interleaved little-endian `i16` bytes.
+///
+/// Unlike the rodio backend, file/pipe targets have no concept of "starts
+/// playing before the source is exhausted", so they just block until the
+/// stream ends and write the whole thing at once.
+fn drain_source_to_pcm_le(source: StreamingSource, speed: f32) -> Vec<u8> {
+    let mut out = Vec::new();
+    for sample in source.speed(speed.clamp(0.5, 2.0)).convert_samples::<i16>() {
+        out.extend_from_slice(&sample.to_le_bytes());
+    }
+    out
+}
+
+/// Writes interleaved PCM to a file (or stdout when the device is `-`/`stdout`).
+pub struct PipeBackend {
+    writer: Box<dyn Write>,
+}
+
+impl PipeBackend {
+    pub fn open(device: Option<String>) -> Result<Box<dyn AudioBackend>, String> {
+        let writer: Box<dyn Write> = match device.as_deref() {
+            None | Some("-") | Some("stdout") => Box::new(std::io::stdout()),
+            Some(path) => Box::new(std::fs::File::create(path).map_err(|e| e.to_string())?),
+        };
+        Ok(Box::new(Self { writer }))
+    }
+}
+
+impl AudioBackend for PipeBackend {
+    fn reset(&mut self) -> Result<(), String> {
+        self.writer.flush().map_err(|e| e.to_string())
+    }
+
+    fn append_wav(&mut self, wav: Vec<u8>, _speed: f32) -> Result<(), String> {
+        let pcm = wav_to_pcm_le(wav)?;
+        self.writer.write_all(&pcm).map_err(|e| e.to_string())?;
+        self.writer.flush().map_err(|e| e.to_string())
+    }
+
+    fn append_source(&mut self, source: StreamingSource, speed: f32) -> Result<(), String> {
+        let pcm = drain_source_to_pcm_le(source, speed);
+        self.writer.write_all(&pcm).map_err(|e| e.to_string())?;
+        self.writer.flush().map_err(|e| e.to_string())
+    }
+
+    fn pause(&mut self) {}
+    fn resume(&mut self) {}
+    fn stop(&mut self) {
+        let _ = self.writer.flush();
+    }
+    fn is_paused(&self) -> bool {
+        false
+    }
+    fn queued_len(&self) -> usize {
+        0
+    }
+    fn is_empty(&self) -> bool {
+        true
+    }
+}
+
+/// Pipes interleaved PCM into an external command's stdin (e.g. a system player).
+pub struct SubprocessBackend {
+    child: Child,
+}
+
+impl SubprocessBackend {
+    pub fn open(device: Option<String>) -> Result<Box<dyn AudioBackend>, String> {
+        let command_line = device.ok_or("subprocess backend requires a command")?;
+        let mut parts = command_line.split_whitespace();
+        let program = parts.next().ok_or("empty subprocess command")?;
+        let child = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|e| e.to_string())?;
+        Ok(Box::new(Self { child }))
+    }
+}
+
+impl AudioBackend for SubprocessBackend {
+    fn reset(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn append_wav(&mut self, wav: Vec<u8>, _speed: f32) -> Result<(), String> {
+        let pcm = wav_to_pcm_le(wav)?;
+        let stdin = self.child.stdin.as_mut().ok_or("subprocess stdin closed")?;
+        stdin.write_all(&pcm).map_err(|e| e.to_string())?;
+        stdin.flush().map_err(|e| e.to_string())
+    }
+
+    fn append_source(&mut self, source: StreamingSource, speed: f32) -> Result<(), String> {
+        let pcm = drain_source_to_pcm_le(source, speed);
+        let stdin = self.child.stdin.as_mut().ok_or("subprocess stdin closed")?;
+        stdin.write_all(&pcm).map_err(|e| e.to_string())?;
+        stdin.flush().map_err(|e| e.to_string())
+    }
+
+    fn pause(&mut self) {}
+    fn resume(&mut self) {}
+    fn stop(&mut self) {
+        let _ = self.child.kill();
+    }
+    fn is_paused(&self) -> bool {
+        false
+    }
+    fn queued_len(&self) -> usize {
+        0
+    }
+    fn is_empty(&self) -> bool {
+        true
+    }
+}
+
+impl Drop for SubprocessBackend {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
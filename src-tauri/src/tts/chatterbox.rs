@@ -7,9 +7,17 @@ use base64::Engine;
 use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 use thiserror::Error;
 
+/// How long `read_response` waits for a line before declaring the sidecar
+/// unresponsive. Generation can legitimately take a while, so this is
+/// generous rather than tuned for snappy failure.
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Error, Debug)]
 pub enum ChatterboxError {
     #[error("Failed to spawn TTS process: {0}")]
@@ -26,6 +34,19 @@ pub enum ChatterboxError {
     SidecarNotFound(String),
 }
 
+impl ChatterboxError {
+    /// Whether this indicates the sidecar process itself died or became
+    /// unreachable (crash, broken pipe, timeout), as opposed to a
+    /// model-level error -- i.e. something `ChatterboxManager`'s
+    /// `auto_restart` might be able to recover from.
+    pub fn is_process_failure(&self) -> bool {
+        matches!(
+            self,
+            ChatterboxError::ProcessNotRunning | ChatterboxError::CommunicationError(_)
+        )
+    }
+}
+
 /// Response from the TTS server
 #[derive(Debug, serde::Deserialize)]
 struct TTSResponse {
@@ -41,6 +62,11 @@ struct TTSResponse {
     device: Option<String>,
     #[serde(default)]
     model_loaded: Option<bool>,
+    /// Echoed back from the request's `id`, if the sidecar supports it.
+    /// Lets `generate` detect a desynced request/response pair instead of
+    /// silently pairing the wrong audio with the wrong chunk.
+    #[serde(default)]
+    id: Option<u64>,
 }
 
 /// TTS generation result
@@ -51,44 +77,187 @@ pub struct ChatterboxResult {
 }
 
 impl ChatterboxResult {
-    /// Convert to WAV bytes
+    /// Convert to 16-bit mono WAV bytes.
     pub fn to_wav(&self) -> Vec<u8> {
-        let num_samples = self.audio.len();
-        let byte_rate = self.sample_rate * 2;
-        let data_size = num_samples * 2;
+        self.to_wav_with_format(1, 16)
+    }
+
+    /// Encode to WAV with an explicit channel count and bit depth. `audio`
+    /// holds mono samples; requesting more than one channel duplicates each
+    /// sample across channels rather than implying genuinely separate
+    /// channel content. Supports 16-, 24-, and 32-bit PCM, falling back to
+    /// 16-bit for any other depth.
+    pub fn to_wav_with_format(&self, channels: u16, bits_per_sample: u16) -> Vec<u8> {
+        let channels = channels.max(1);
+        let bytes_per_sample: u32 = match bits_per_sample {
+            24 => 3,
+            32 => 4,
+            _ => 2,
+        };
+        let bits_per_sample = bytes_per_sample * 8;
+
+        let block_align = channels as u32 * bytes_per_sample;
+        let byte_rate = self.sample_rate * block_align;
+        let data_size = self.audio.len() as u32 * block_align;
         let file_size = 36 + data_size;
 
-        let mut buffer = Vec::with_capacity(44 + data_size);
+        let mut buffer = Vec::with_capacity(44 + data_size as usize);
 
         buffer.extend_from_slice(b"RIFF");
-        buffer.extend_from_slice(&(file_size as u32).to_le_bytes());
+        buffer.extend_from_slice(&file_size.to_le_bytes());
         buffer.extend_from_slice(b"WAVE");
         buffer.extend_from_slice(b"fmt ");
         buffer.extend_from_slice(&16u32.to_le_bytes());
         buffer.extend_from_slice(&1u16.to_le_bytes()); // PCM format
-        buffer.extend_from_slice(&1u16.to_le_bytes()); // Mono
+        buffer.extend_from_slice(&channels.to_le_bytes());
         buffer.extend_from_slice(&self.sample_rate.to_le_bytes());
         buffer.extend_from_slice(&byte_rate.to_le_bytes());
-        buffer.extend_from_slice(&2u16.to_le_bytes()); // Block align
-        buffer.extend_from_slice(&16u16.to_le_bytes()); // Bits per sample
+        buffer.extend_from_slice(&(block_align as u16).to_le_bytes());
+        buffer.extend_from_slice(&(bits_per_sample as u16).to_le_bytes());
         buffer.extend_from_slice(b"data");
-        buffer.extend_from_slice(&(data_size as u32).to_le_bytes());
+        buffer.extend_from_slice(&data_size.to_le_bytes());
 
         for sample in &self.audio {
             let clamped = sample.clamp(-1.0, 1.0);
-            let int_sample = (clamped * 32767.0) as i16;
-            buffer.extend_from_slice(&int_sample.to_le_bytes());
+            for _ in 0..channels {
+                match bytes_per_sample {
+                    3 => {
+                        let v = (clamped * 8_388_607.0) as i32;
+                        buffer.extend_from_slice(&v.to_le_bytes()[0..3]);
+                    }
+                    4 => {
+                        let v = (clamped * 2_147_483_647.0) as i32;
+                        buffer.extend_from_slice(&v.to_le_bytes());
+                    }
+                    _ => {
+                        let v = (clamped * 32767.0) as i16;
+                        buffer.extend_from_slice(&v.to_le_bytes());
+                    }
+                }
+            }
         }
 
         buffer
     }
 }
 
+/// Per-engine capabilities, so callers (and the frontend) can grey out
+/// controls a backend doesn't support instead of discovering it only when a
+/// command fails.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+pub struct Features {
+    pub emotion_control: bool,
+    pub voice_cloning: bool,
+    pub streaming: bool,
+    pub native_sample_rate: u32,
+}
+
+/// Parameters for a single `generate` call. A backend ignores whichever
+/// fields it doesn't support rather than erroring on them.
+#[derive(Debug, Clone)]
+pub struct SynthOptions {
+    pub speed: f32,
+    pub temperature: f32,
+    /// Emotion/exaggeration intensity. Chatterbox-specific; engines without
+    /// an expressiveness control ignore it.
+    pub exaggeration: f32,
+    /// Classifier-free guidance weight, trading adherence to the
+    /// text/reference conditioning against naturalness.
+    pub cfg_weight: f32,
+    /// Fixed seed for reproducible output. `None` lets the engine pick its
+    /// own (non-reproducible) seed, matching prior behavior.
+    pub seed: Option<u64>,
+    /// Extra per-engine fields merged directly into the JSON command, for
+    /// controls that only apply to one engine (e.g. a Qwen3-TTS voice id).
+    /// The sidecar ignores fields it doesn't recognize.
+    pub extra_params: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+impl Default for SynthOptions {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            temperature: 0.1,
+            exaggeration: 0.5,
+            cfg_weight: 0.5,
+            seed: None,
+            extra_params: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+/// A voice-cloning reference clip: either a WAV file on disk or
+/// already-decoded PCM samples at a known sample rate.
+pub enum ReferenceAudio {
+    Path(PathBuf),
+    Samples { samples: Vec<f32>, sample_rate: u32 },
+}
+
+/// One interface over every TTS engine this app can drive. `ChatterboxManager`
+/// holds whichever engine is selected behind a single `Box<dyn TtsBackend>`,
+/// so a pure-Rust in-process engine or an HTTP-based remote one could be
+/// added later (see `build_backend`) without touching any caller.
+pub trait TtsBackend: Send {
+    /// Launch the engine's process/connection, if it has one.
+    fn start(&mut self) -> Result<(), ChatterboxError>;
+    /// Load the model, returning a description of the device it loaded onto.
+    fn init_model(&mut self) -> Result<String, ChatterboxError>;
+    /// Prime caches for faster first generation. Backends that don't need
+    /// this can no-op.
+    fn warmup(&mut self) -> Result<(), ChatterboxError>;
+    fn generate(
+        &mut self,
+        text: &str,
+        options: &SynthOptions,
+    ) -> Result<ChatterboxResult, ChatterboxError>;
+    fn is_ready(&mut self) -> bool;
+    fn is_initialized(&self) -> bool;
+    fn shutdown(&mut self);
+    /// This backend's supported capabilities.
+    fn features(&self) -> Features;
+    /// Use `reference` as a voice-cloning conditioning clip for subsequent
+    /// `generate` calls, if supported (see `features().voice_cloning`).
+    fn set_reference_voice(&mut self, _reference: ReferenceAudio) -> Result<(), ChatterboxError> {
+        Err(ChatterboxError::GenerationError(
+            "this engine does not support reference-audio voice cloning".to_string(),
+        ))
+    }
+    /// Clear a reference voice set via `set_reference_voice`, returning to
+    /// the engine's default speaker. No-op by default, since a backend that
+    /// never accepted one has nothing to clear.
+    fn clear_reference_voice(&mut self) -> Result<(), ChatterboxError> {
+        Ok(())
+    }
+}
+
 /// TTS process manager (Handles Chatterbox and Qwen3-TTS engines)
 pub struct ChatterboxTTS {
     process: Option<Child>,
     initialized: bool,
     engine: TTSEngine,
+    /// Lines from the sidecar's stdout, forwarded by a dedicated reader
+    /// thread so `read_response` can wait on them with a deadline instead of
+    /// blocking forever on a hung or dead process.
+    responses: Option<mpsc::Receiver<std::io::Result<String>>>,
+    read_timeout: Duration,
+    /// Incrementing id attached to every request and checked against the
+    /// response's echoed `id`, to catch a desynced request/response pairing
+    /// rather than silently handing back the wrong chunk's audio.
+    next_seq: AtomicU64,
+    /// The last reference voice applied via `set_reference_voice`, cached as
+    /// (wav bytes, sample rate) so it can be silently resent after a
+    /// crash/restart -- the sidecar only holds it in memory.
+    reference_voice: Option<(Vec<u8>, u32)>,
+}
+
+/// Parsed `fmt ` fields and the `data` chunk's bytes from a WAV file, as
+/// returned by `ChatterboxTTS::scan_wav`.
+struct WavChunks<'a> {
+    format_tag: u16,
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    data: &'a [u8],
 }
 
 impl ChatterboxTTS {
@@ -97,9 +266,19 @@ impl ChatterboxTTS {
             process: None,
             initialized: false,
             engine,
+            responses: None,
+            read_timeout: DEFAULT_READ_TIMEOUT,
+            next_seq: AtomicU64::new(0),
+            reference_voice: None,
         }
     }
 
+    /// Override how long `read_response` waits for the sidecar before
+    /// treating it as unresponsive.
+    pub fn set_read_timeout(&mut self, timeout: Duration) {
+        self.read_timeout = timeout;
+    }
+
     /// Get the path to the bundled sidecar executable
     fn get_sidecar_path(&self) -> Result<PathBuf, ChatterboxError> {
         let current_dir = std::env::current_dir().unwrap_or_default();
@@ -125,6 +304,14 @@ impl ChatterboxTTS {
                         "qwen3_tts_cuda.py"
                     }
                 }
+                // This legacy sidecar manager predates Echo and System. It is
+                // built with the default engine, but is only started or used
+                // after `set_engine` has selected a sidecar engine.
+                TTSEngine::Echo | TTSEngine::System => {
+                    unreachable!(
+                        "ChatterboxTTS only drives the legacy Chatterbox/Qwen3TTS sidecars"
+                    )
+                }
             };
 
             let mut possible_paths = vec![
@@ -178,6 +365,9 @@ impl ChatterboxTTS {
                     "qwen3-tts-cuda"
                 }
             }
+            TTSEngine::Echo | TTSEngine::System => {
+                unreachable!("ChatterboxTTS only drives the legacy Chatterbox/Qwen3TTS sidecars")
+            }
         };
 
         // Define sidecar suffix based on target platform
@@ -306,6 +496,7 @@ impl ChatterboxTTS {
         };
 
         self.process = Some(child);
+        self.spawn_reader()?;
 
         // Wait for ready signal
         let response = self.read_response()?;
@@ -315,6 +506,10 @@ impl ChatterboxTTS {
             ));
         }
 
+        // The sidecar only holds a reference voice in memory, so a fresh
+        // process (including one spun up by auto-restart) needs it resent.
+        self.reapply_reference_voice()?;
+
         Ok(())
     }
 
@@ -375,22 +570,44 @@ impl ChatterboxTTS {
     pub fn generate(
         &mut self,
         text: &str,
-        speed: f32,
+        options: &SynthOptions,
     ) -> Result<ChatterboxResult, ChatterboxError> {
         if !self.initialized {
             return Err(ChatterboxError::ProcessNotRunning);
         }
 
-        let cmd = serde_json::json!({
+        let seq = self.next_seq.fetch_add(1, Ordering::SeqCst);
+        let mut cmd = serde_json::json!({
             "action": "generate",
+            "id": seq,
             "text": text,
-            "speed": speed,
-            "temperature": 0.1,
+            "speed": options.speed,
+            "temperature": options.temperature,
+            "exaggeration": options.exaggeration,
+            "cfg_weight": options.cfg_weight,
         });
 
+        if let Some(seed) = options.seed {
+            cmd["seed"] = serde_json::json!(seed);
+        }
+
+        if let serde_json::Value::Object(map) = &mut cmd {
+            for (key, value) in &options.extra_params {
+                map.insert(key.clone(), value.clone());
+            }
+        }
+
         self.send_command(&cmd)?;
         let response = self.read_response()?;
 
+        if let Some(id) = response.id {
+            if id != seq {
+                return Err(ChatterboxError::InvalidResponse(format!(
+                    "sidecar response out of order: expected id {seq}, got {id}"
+                )));
+            }
+        }
+
         if response.status != "ok" {
             return Err(ChatterboxError::GenerationError(
                 response
@@ -415,25 +632,22 @@ impl ChatterboxTTS {
         Ok(ChatterboxResult { audio, sample_rate })
     }
 
-    /// Parse WAV bytes and extract f32 samples
-    fn wav_to_samples(wav_bytes: &[u8]) -> Result<Vec<f32>, ChatterboxError> {
-        // Simple WAV parser - assumes 16-bit PCM mono
-        if wav_bytes.len() < 44 {
-            return Err(ChatterboxError::InvalidResponse(
-                "WAV data too short".to_string(),
-            ));
-        }
-
-        // Verify RIFF header
-        if &wav_bytes[0..4] != b"RIFF" || &wav_bytes[8..12] != b"WAVE" {
+    /// Scan a WAV file's chunks, validating the RIFF/WAVE header and
+    /// returning its `fmt ` fields alongside a slice of the `data` chunk's
+    /// bytes. Shared by `wav_to_samples` (full decode) and `wav_sample_rate`
+    /// (reference-voice metadata only).
+    fn scan_wav(wav_bytes: &[u8]) -> Result<WavChunks<'_>, ChatterboxError> {
+        if wav_bytes.len() < 12 || &wav_bytes[0..4] != b"RIFF" || &wav_bytes[8..12] != b"WAVE" {
             return Err(ChatterboxError::InvalidResponse(
                 "Invalid WAV header".to_string(),
             ));
         }
 
-        // Find data chunk
         let mut pos = 12;
-        while pos + 8 < wav_bytes.len() {
+        let mut format: Option<(u16, u16, u32, u16)> = None; // (tag, channels, sample_rate, bits_per_sample)
+        let mut data: Option<&[u8]> = None;
+
+        while pos + 8 <= wav_bytes.len() {
             let chunk_id = &wav_bytes[pos..pos + 4];
             let chunk_size = u32::from_le_bytes([
                 wav_bytes[pos + 4],
@@ -441,31 +655,203 @@ impl ChatterboxTTS {
                 wav_bytes[pos + 6],
                 wav_bytes[pos + 7],
             ]) as usize;
+            let chunk_start = pos + 8;
+            let chunk_end = (chunk_start + chunk_size).min(wav_bytes.len());
+
+            if chunk_id == b"fmt " {
+                if chunk_start + 16 > wav_bytes.len() {
+                    return Err(ChatterboxError::InvalidResponse(
+                        "Truncated fmt chunk".to_string(),
+                    ));
+                }
+                let format_tag =
+                    u16::from_le_bytes([wav_bytes[chunk_start], wav_bytes[chunk_start + 1]]);
+                let channels =
+                    u16::from_le_bytes([wav_bytes[chunk_start + 2], wav_bytes[chunk_start + 3]]);
+                let sample_rate = u32::from_le_bytes([
+                    wav_bytes[chunk_start + 4],
+                    wav_bytes[chunk_start + 5],
+                    wav_bytes[chunk_start + 6],
+                    wav_bytes[chunk_start + 7],
+                ]);
+                let bits_per_sample =
+                    u16::from_le_bytes([wav_bytes[chunk_start + 14], wav_bytes[chunk_start + 15]]);
+                format = Some((format_tag, channels, sample_rate, bits_per_sample));
+            } else if chunk_id == b"data" {
+                data = Some(&wav_bytes[chunk_start..chunk_end]);
+            }
+
+            pos = chunk_start + chunk_size + (chunk_size % 2);
+        }
+
+        let (format_tag, channels, sample_rate, bits_per_sample) = format
+            .ok_or_else(|| ChatterboxError::InvalidResponse("No fmt chunk in WAV".to_string()))?;
+        let data = data
+            .ok_or_else(|| ChatterboxError::InvalidResponse("No data chunk in WAV".to_string()))?;
+
+        Ok(WavChunks {
+            format_tag,
+            channels,
+            sample_rate,
+            bits_per_sample,
+            data,
+        })
+    }
+
+    /// Read the sample rate out of a WAV file's `fmt ` chunk, for
+    /// `set_reference_voice`'s path case.
+    fn wav_sample_rate(wav_bytes: &[u8]) -> Result<u32, ChatterboxError> {
+        Ok(Self::scan_wav(wav_bytes)?.sample_rate)
+    }
 
-            if chunk_id == b"data" {
-                let data_start = pos + 8;
-                let data_end = (data_start + chunk_size).min(wav_bytes.len());
+    /// Decode PCM/IEEE-float samples from a WAV file's `data` chunk,
+    /// downmixing to mono by averaging channels. Supports 16/24/32-bit
+    /// integer PCM (format tag 1) and 32-bit IEEE float (format tag 3).
+    fn wav_to_samples(wav_bytes: &[u8]) -> Result<Vec<f32>, ChatterboxError> {
+        let wav = Self::scan_wav(wav_bytes)?;
+        let channels = wav.channels.max(1) as usize;
+        let bytes_per_sample = (wav.bits_per_sample / 8) as usize;
+
+        if bytes_per_sample == 0 {
+            return Err(ChatterboxError::InvalidResponse(format!(
+                "Unsupported bits-per-sample: {}",
+                wav.bits_per_sample
+            )));
+        }
 
-                // Convert 16-bit samples to f32
-                let mut samples = Vec::with_capacity((data_end - data_start) / 2);
-                for chunk in wav_bytes[data_start..data_end].chunks_exact(2) {
-                    let sample = i16::from_le_bytes([chunk[0], chunk[1]]);
-                    samples.push(sample as f32 / 32768.0);
+        let decode_sample: fn(&[u8]) -> Result<f32, ChatterboxError> =
+            match (wav.format_tag, wav.bits_per_sample) {
+                (1, 16) => |b| Ok(i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0),
+                (1, 24) => |b| {
+                    let sign_byte = if b[2] & 0x80 != 0 { 0xFF } else { 0x00 };
+                    let v = i32::from_le_bytes([b[0], b[1], b[2], sign_byte]);
+                    Ok(v as f32 / 8_388_608.0)
+                },
+                (1, 32) => {
+                    |b| Ok(i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0)
+                }
+                (3, 32) => |b| Ok(f32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+                (tag, bits) => {
+                    return Err(ChatterboxError::InvalidResponse(format!(
+                        "Unsupported WAV format: tag {tag}, {bits}-bit"
+                    )))
                 }
+            };
+
+        let frame_size = channels * bytes_per_sample;
+        if wav.data.len() % frame_size != 0 {
+            return Err(ChatterboxError::InvalidResponse(format!(
+                "data chunk length {} is not a multiple of frame size {} ({} channel(s) x {} bytes/sample)",
+                wav.data.len(),
+                frame_size,
+                channels,
+                bytes_per_sample
+            )));
+        }
 
-                return Ok(samples);
+        let mut samples = Vec::with_capacity(wav.data.len() / frame_size);
+        for frame in wav.data.chunks_exact(frame_size) {
+            let mut sum = 0.0f32;
+            for ch in 0..channels {
+                let start = ch * bytes_per_sample;
+                sum += decode_sample(&frame[start..start + bytes_per_sample])?;
             }
+            samples.push(sum / channels as f32);
+        }
 
-            pos += 8 + chunk_size;
-            // Align to word boundary
-            if chunk_size % 2 != 0 {
-                pos += 1;
+        Ok(samples)
+    }
+
+    /// Use `reference` as a voice-cloning conditioning clip for subsequent
+    /// `generate` calls, and cache it so it survives a sidecar restart.
+    pub fn set_reference_voice(
+        &mut self,
+        reference: ReferenceAudio,
+    ) -> Result<(), ChatterboxError> {
+        let (wav_bytes, sample_rate) = match reference {
+            ReferenceAudio::Path(path) => {
+                let bytes = std::fs::read(&path).map_err(|e| {
+                    ChatterboxError::InvalidResponse(format!(
+                        "failed to read reference audio {:?}: {e}",
+                        path
+                    ))
+                })?;
+                let sample_rate = Self::wav_sample_rate(&bytes)?;
+                (bytes, sample_rate)
+            }
+            ReferenceAudio::Samples {
+                samples,
+                sample_rate,
+            } => {
+                let wav_bytes = ChatterboxResult {
+                    audio: samples,
+                    sample_rate,
+                }
+                .to_wav();
+                (wav_bytes, sample_rate)
             }
+        };
+
+        self.apply_reference_voice(&wav_bytes, sample_rate)?;
+        self.reference_voice = Some((wav_bytes, sample_rate));
+        Ok(())
+    }
+
+    /// Stop using a reference voice; subsequent `generate` calls fall back
+    /// to the engine's default speaker.
+    pub fn clear_reference_voice(&mut self) -> Result<(), ChatterboxError> {
+        self.reference_voice = None;
+
+        let cmd = serde_json::json!({
+            "action": "clear_reference",
+        });
+        self.send_command(&cmd)?;
+        let response = self.read_response()?;
+
+        if response.status != "ok" {
+            return Err(ChatterboxError::GenerationError(
+                response
+                    .error
+                    .unwrap_or_else(|| "Failed to clear reference voice".to_string()),
+            ));
         }
 
-        Err(ChatterboxError::InvalidResponse(
-            "No data chunk in WAV".to_string(),
-        ))
+        Ok(())
+    }
+
+    fn apply_reference_voice(
+        &mut self,
+        wav_bytes: &[u8],
+        sample_rate: u32,
+    ) -> Result<(), ChatterboxError> {
+        let audio_b64 = base64::engine::general_purpose::STANDARD.encode(wav_bytes);
+        let cmd = serde_json::json!({
+            "action": "set_reference",
+            "audio": audio_b64,
+            "sample_rate": sample_rate,
+        });
+
+        self.send_command(&cmd)?;
+        let response = self.read_response()?;
+
+        if response.status != "ok" {
+            return Err(ChatterboxError::GenerationError(
+                response
+                    .error
+                    .unwrap_or_else(|| "Failed to set reference voice".to_string()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Resend the cached reference voice, if any, after `start()` -- the
+    /// sidecar only holds it in memory and loses it across restarts.
+    fn reapply_reference_voice(&mut self) -> Result<(), ChatterboxError> {
+        if let Some((wav_bytes, sample_rate)) = self.reference_voice.clone() {
+            self.apply_reference_voice(&wav_bytes, sample_rate)?;
+        }
+        Ok(())
     }
 
     /// Check if the model is loaded
@@ -493,6 +879,29 @@ impl ChatterboxTTS {
         self.initialized
     }
 
+    /// This engine's supported capabilities. Chatterbox's `exaggeration`
+    /// option is its emotion control; Qwen3-TTS has no equivalent knob, so
+    /// only Chatterbox reports `emotion_control`.
+    pub fn features(&self) -> Features {
+        match self.engine {
+            TTSEngine::Qwen3TTS => Features {
+                emotion_control: false,
+                voice_cloning: true,
+                streaming: false,
+                native_sample_rate: 24000,
+            },
+            TTSEngine::Chatterbox => Features {
+                emotion_control: true,
+                voice_cloning: true,
+                streaming: false,
+                native_sample_rate: 24000,
+            },
+            TTSEngine::Echo | TTSEngine::System => {
+                unreachable!("ChatterboxTTS only drives the legacy Chatterbox/Qwen3TTS sidecars")
+            }
+        }
+    }
+
     /// Shutdown the TTS process
     pub fn shutdown(&mut self) {
         let cmd = serde_json::json!({
@@ -504,6 +913,7 @@ impl ChatterboxTTS {
             let _ = process.kill();
         }
         self.process = None;
+        self.responses = None;
         self.initialized = false;
     }
 
@@ -531,23 +941,104 @@ impl ChatterboxTTS {
         Ok(())
     }
 
-    fn read_response(&mut self) -> Result<TTSResponse, ChatterboxError> {
+    /// Take ownership of the child's stdout and hand it to a dedicated
+    /// thread that blocks on `read_line` in a loop, forwarding each line (or
+    /// the terminal IO error) over a channel. This is what lets
+    /// `read_response` wait with a timeout instead of blocking forever.
+    fn spawn_reader(&mut self) -> Result<(), ChatterboxError> {
         let process = self
             .process
             .as_mut()
             .ok_or(ChatterboxError::ProcessNotRunning)?;
-
         let stdout = process
             .stdout
-            .as_mut()
+            .take()
             .ok_or(ChatterboxError::ProcessNotRunning)?;
 
-        let mut reader = BufReader::new(stdout);
-        let mut line = String::new();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut reader = BufReader::new(stdout);
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) => {
+                        let _ = tx.send(Err(std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            "sidecar closed stdout",
+                        )));
+                        break;
+                    }
+                    Ok(_) => {
+                        if tx.send(Ok(std::mem::take(&mut line))).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Err(e));
+                        break;
+                    }
+                }
+            }
+        });
+
+        self.responses = Some(rx);
+        Ok(())
+    }
+
+    /// Describe why the sidecar is no longer answering, for error messages.
+    /// Surfaces the exit code (or, on Unix, the terminating signal) instead
+    /// of a generic "communication failed".
+    fn describe_exit_status(&mut self) -> String {
+        let Some(process) = self.process.as_mut() else {
+            return "sidecar process not running".to_string();
+        };
 
-        reader
-            .read_line(&mut line)
-            .map_err(|e| ChatterboxError::CommunicationError(e.to_string()))?;
+        match process.try_wait() {
+            Ok(Some(status)) => {
+                #[cfg(unix)]
+                {
+                    use std::os::unix::process::ExitStatusExt;
+                    if let Some(signal) = status.signal() {
+                        return format!("sidecar terminated by signal {signal}");
+                    }
+                }
+                format!("sidecar exited with {status}")
+            }
+            Ok(None) => "sidecar still running but unresponsive".to_string(),
+            Err(e) => format!("failed to check sidecar status: {e}"),
+        }
+    }
+
+    fn read_response(&mut self) -> Result<TTSResponse, ChatterboxError> {
+        let timeout = self.read_timeout;
+        let recv_result = self
+            .responses
+            .as_ref()
+            .ok_or(ChatterboxError::ProcessNotRunning)?
+            .recv_timeout(timeout);
+
+        let line = match recv_result {
+            Ok(Ok(line)) => line,
+            Ok(Err(io_err)) => {
+                return Err(ChatterboxError::CommunicationError(format!(
+                    "{io_err} ({})",
+                    self.describe_exit_status()
+                )))
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                return Err(ChatterboxError::CommunicationError(format!(
+                    "timeout waiting for sidecar response ({})",
+                    self.describe_exit_status()
+                )))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(ChatterboxError::CommunicationError(format!(
+                    "sidecar reader thread exited ({})",
+                    self.describe_exit_status()
+                )))
+            }
+        };
 
         serde_json::from_str(&line)
             .map_err(|e| ChatterboxError::InvalidResponse(format!("JSON parse error: {}", e)))
@@ -566,81 +1057,307 @@ impl Drop for ChatterboxTTS {
     }
 }
 
-/// Thread-safe wrapper for ChatterboxTTS
+impl TtsBackend for ChatterboxTTS {
+    fn start(&mut self) -> Result<(), ChatterboxError> {
+        ChatterboxTTS::start(self)
+    }
+
+    fn init_model(&mut self) -> Result<String, ChatterboxError> {
+        ChatterboxTTS::init_model(self)
+    }
+
+    fn warmup(&mut self) -> Result<(), ChatterboxError> {
+        ChatterboxTTS::warmup(self)
+    }
+
+    fn generate(
+        &mut self,
+        text: &str,
+        options: &SynthOptions,
+    ) -> Result<ChatterboxResult, ChatterboxError> {
+        ChatterboxTTS::generate(self, text, options)
+    }
+
+    fn is_ready(&mut self) -> bool {
+        ChatterboxTTS::is_ready(self)
+    }
+
+    fn is_initialized(&self) -> bool {
+        ChatterboxTTS::is_initialized(self)
+    }
+
+    fn shutdown(&mut self) {
+        ChatterboxTTS::shutdown(self)
+    }
+
+    fn features(&self) -> Features {
+        ChatterboxTTS::features(self)
+    }
+
+    fn set_reference_voice(&mut self, reference: ReferenceAudio) -> Result<(), ChatterboxError> {
+        ChatterboxTTS::set_reference_voice(self, reference)
+    }
+
+    fn clear_reference_voice(&mut self) -> Result<(), ChatterboxError> {
+        ChatterboxTTS::clear_reference_voice(self)
+    }
+}
+
+/// Build the backend for a given engine selection. The legacy sidecar engines
+/// (Chatterbox/Qwen3-TTS) share `ChatterboxTTS`; a future in-process or
+/// remote engine would get its own `TtsBackend` impl and a new arm here.
+fn build_backend(engine: TTSEngine) -> Box<dyn TtsBackend> {
+    Box::new(ChatterboxTTS::new(engine))
+}
+
+/// Re-spawn the sidecar and replay `init_model`/`warmup`, retrying
+/// `generate` after each attempt, up to `max_restart_attempts` times. Shared
+/// between `ChatterboxManager::generate` and `generate_stream`'s background
+/// thread, which don't hold a `&ChatterboxManager` to call a method on.
+fn restart_and_retry(
+    backend: &mut Box<dyn TtsBackend>,
+    max_restart_attempts: u32,
+    text: &str,
+    options: &SynthOptions,
+) -> Result<ChatterboxResult, ChatterboxError> {
+    let mut last_err = ChatterboxError::ProcessNotRunning;
+
+    for _ in 0..max_restart_attempts {
+        backend.shutdown();
+        if let Err(e) = backend.start() {
+            last_err = e;
+            continue;
+        }
+        if let Err(e) = backend.init_model() {
+            last_err = e;
+            continue;
+        }
+        // Best-effort -- engines that don't need warmup just no-op it.
+        let _ = backend.warmup();
+
+        match backend.generate(text, options) {
+            Ok(result) => return Ok(result),
+            Err(e) => last_err = e,
+        }
+    }
+
+    Err(last_err)
+}
+
+/// Lock `inner`, generate, and fall back to `restart_and_retry` on a
+/// process-level failure if `auto_restart` is enabled.
+fn generate_with_restart(
+    inner: &Mutex<Box<dyn TtsBackend>>,
+    auto_restart: bool,
+    max_restart_attempts: u32,
+    text: &str,
+    options: &SynthOptions,
+) -> Result<ChatterboxResult, ChatterboxError> {
+    let mut backend = inner
+        .lock()
+        .map_err(|_| ChatterboxError::CommunicationError("Failed to acquire lock".to_string()))?;
+
+    match backend.generate(text, options) {
+        Err(e) if e.is_process_failure() && auto_restart => {
+            restart_and_retry(&mut backend, max_restart_attempts, text, options)
+        }
+        result => result,
+    }
+}
+
+/// A cooperative stop flag for `generate_stream`. Cloning shares the same
+/// underlying flag, so the host's "stop playback" handler can hold one end
+/// and call `cancel()` while the generation thread polls the other.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Thread-safe wrapper holding whichever `TtsBackend` is currently selected.
 pub struct ChatterboxManager {
-    inner: Mutex<ChatterboxTTS>,
+    inner: Arc<Mutex<Box<dyn TtsBackend>>>,
+    /// When set, `generate`/`generate_stream` re-spawn the sidecar and
+    /// replay `init_model`/`warmup` after a process-level failure instead of
+    /// surfacing it immediately. Off by default so existing callers see
+    /// unchanged behavior until they opt in.
+    auto_restart: AtomicBool,
+    /// Upper bound on restart attempts per chunk, so a permanently-broken
+    /// sidecar fails instead of retrying forever.
+    max_restart_attempts: u32,
 }
 
 impl ChatterboxManager {
     pub fn new() -> Self {
         Self {
-            inner: Mutex::new(ChatterboxTTS::new(TTSEngine::default())),
+            inner: Arc::new(Mutex::new(build_backend(TTSEngine::default()))),
+            auto_restart: AtomicBool::new(false),
+            max_restart_attempts: 3,
         }
     }
 
+    /// Enable or disable automatic sidecar restart after a crash.
+    pub fn set_auto_restart(&self, enabled: bool) {
+        self.auto_restart.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Switch to a different engine. Shuts down whatever is currently running
+    /// and replaces it with a freshly built backend for `engine`.
     pub fn set_engine(&self, engine: TTSEngine) -> Result<(), ChatterboxError> {
-        let mut tts = self.inner.lock().map_err(|_| {
+        let mut backend = self.inner.lock().map_err(|_| {
             ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
         })?;
 
-        // Shutdown current engine
-        tts.shutdown();
-
-        // Create new engine instance inside the mutex (or just update state)
-        // Since we refactored valid struct to hold state, we can just update the engine field and it will use new binary on next start
-        tts.engine = engine;
+        backend.shutdown();
+        *backend = build_backend(engine);
 
         Ok(())
     }
 
     pub fn start(&self) -> Result<(), ChatterboxError> {
-        let mut tts = self.inner.lock().map_err(|_| {
+        let mut backend = self.inner.lock().map_err(|_| {
             ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
         })?;
-        tts.start()
+        backend.start()
     }
 
     pub fn init_model(&self) -> Result<String, ChatterboxError> {
-        let mut tts = self.inner.lock().map_err(|_| {
+        let mut backend = self.inner.lock().map_err(|_| {
             ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
         })?;
-        tts.init_model()
+        backend.init_model()
     }
 
-    pub fn generate(&self, text: &str, speed: f32) -> Result<ChatterboxResult, ChatterboxError> {
-        let mut tts = self.inner.lock().map_err(|_| {
-            ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
-        })?;
-        tts.generate(text, speed)
+    pub fn generate(
+        &self,
+        text: &str,
+        options: &SynthOptions,
+    ) -> Result<ChatterboxResult, ChatterboxError> {
+        generate_with_restart(
+            &self.inner,
+            self.auto_restart.load(Ordering::SeqCst),
+            self.max_restart_attempts,
+            text,
+            options,
+        )
+    }
+
+    /// Synthesize `text` chunk-by-chunk (via `split_into_chunks`), delivering
+    /// each finished `ChatterboxResult` to `on_chunk` in order, tagged with
+    /// its sequence number, as soon as it decodes.
+    ///
+    /// The sidecar is a single process handling one request at a time, so
+    /// chunks are still submitted to it sequentially -- but `on_chunk` is
+    /// expected to hand the audio off quickly (e.g. queue it for playback)
+    /// rather than block until it finishes playing. As long as it does,
+    /// generation of chunk N+1 starts immediately after chunk N is decoded,
+    /// overlapping with the host playing chunk N instead of waiting for it.
+    ///
+    /// `cancel` stops chunks not yet submitted to the sidecar; a chunk
+    /// already in flight when cancelled is allowed to finish (the sidecar's
+    /// request/response protocol has no way to abort mid-flight), and its
+    /// result is dropped rather than delivered.
+    pub fn generate_stream(
+        &self,
+        text: &str,
+        options: SynthOptions,
+        max_chars: usize,
+        cancel: CancellationToken,
+        mut on_chunk: impl FnMut(usize, ChatterboxResult) + Send + 'static,
+    ) {
+        let chunks = split_into_chunks(text, max_chars);
+        let inner = Arc::clone(&self.inner);
+        let auto_restart = self.auto_restart.load(Ordering::SeqCst);
+        let max_restart_attempts = self.max_restart_attempts;
+
+        thread::spawn(move || {
+            for (seq, chunk) in chunks.into_iter().enumerate() {
+                if cancel.is_cancelled() {
+                    break;
+                }
+
+                match generate_with_restart(
+                    &inner,
+                    auto_restart,
+                    max_restart_attempts,
+                    &chunk,
+                    &options,
+                ) {
+                    Ok(result) => {
+                        if cancel.is_cancelled() {
+                            break;
+                        }
+                        on_chunk(seq, result);
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
     }
 
     pub fn is_ready(&self) -> bool {
-        if let Ok(mut tts) = self.inner.lock() {
-            tts.is_ready()
+        if let Ok(mut backend) = self.inner.lock() {
+            backend.is_ready()
         } else {
             false
         }
     }
 
     pub fn is_initialized(&self) -> bool {
-        if let Ok(tts) = self.inner.lock() {
-            tts.is_initialized()
+        if let Ok(backend) = self.inner.lock() {
+            backend.is_initialized()
         } else {
             false
         }
     }
 
     pub fn shutdown(&self) {
-        if let Ok(mut tts) = self.inner.lock() {
-            tts.shutdown();
+        if let Ok(mut backend) = self.inner.lock() {
+            backend.shutdown();
         }
     }
 
     pub fn warmup(&self) -> Result<(), ChatterboxError> {
-        let mut tts = self.inner.lock().map_err(|_| {
+        let mut backend = self.inner.lock().map_err(|_| {
+            ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
+        })?;
+        backend.warmup()
+    }
+
+    /// The currently-selected backend's supported capabilities.
+    pub fn features(&self) -> Features {
+        self.inner
+            .lock()
+            .map(|backend| backend.features())
+            .unwrap_or_default()
+    }
+
+    /// Use `reference` as a voice-cloning conditioning clip for subsequent
+    /// `generate` calls, if the current backend supports it.
+    pub fn set_reference_voice(&self, reference: ReferenceAudio) -> Result<(), ChatterboxError> {
+        let mut backend = self.inner.lock().map_err(|_| {
+            ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
+        })?;
+        backend.set_reference_voice(reference)
+    }
+
+    /// Stop using a reference voice, returning to the default speaker.
+    pub fn clear_reference_voice(&self) -> Result<(), ChatterboxError> {
+        let mut backend = self.inner.lock().map_err(|_| {
             ChatterboxError::CommunicationError("Failed to acquire lock".to_string())
         })?;
-        tts.warmup()
+        backend.clear_reference_voice()
     }
 }
 
@@ -671,3 +1388,123 @@ pub fn split_into_chunks(text: &str, max_chars: usize) -> Vec<String> {
 
     chunks
 }
+
+#[cfg(test)]
+mod wav_tests {
+    use super::*;
+
+    /// Hand-build a minimal WAV with an explicit format tag/bit depth,
+    /// since `ChatterboxResult::to_wav_with_format` only ever emits PCM
+    /// (tag 1) -- there's no built-in encoder for IEEE float (tag 3).
+    fn wav_bytes(format_tag: u16, channels: u16, sample_rate: u32, data: &[u8]) -> Vec<u8> {
+        let bits_per_sample = match format_tag {
+            3 => 32,
+            _ => 16,
+        };
+        let block_align = channels as u32 * (bits_per_sample as u32 / 8);
+        let byte_rate = sample_rate * block_align;
+        let data_size = data.len() as u32;
+
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(b"RIFF");
+        buffer.extend_from_slice(&(36 + data_size).to_le_bytes());
+        buffer.extend_from_slice(b"WAVE");
+        buffer.extend_from_slice(b"fmt ");
+        buffer.extend_from_slice(&16u32.to_le_bytes());
+        buffer.extend_from_slice(&format_tag.to_le_bytes());
+        buffer.extend_from_slice(&channels.to_le_bytes());
+        buffer.extend_from_slice(&sample_rate.to_le_bytes());
+        buffer.extend_from_slice(&byte_rate.to_le_bytes());
+        buffer.extend_from_slice(&(block_align as u16).to_le_bytes());
+        buffer.extend_from_slice(&bits_per_sample.to_le_bytes());
+        buffer.extend_from_slice(b"data");
+        buffer.extend_from_slice(&data_size.to_le_bytes());
+        buffer.extend_from_slice(data);
+        buffer
+    }
+
+    #[test]
+    fn rejects_a_buffer_without_a_riff_wave_header() {
+        let err = ChatterboxTTS::scan_wav(b"not a wav file").unwrap_err();
+        assert!(matches!(err, ChatterboxError::InvalidResponse(_)));
+    }
+
+    #[test]
+    fn reads_the_sample_rate_out_of_the_fmt_chunk() {
+        let wav = wav_bytes(1, 1, 22050, &0i16.to_le_bytes());
+        assert_eq!(ChatterboxTTS::wav_sample_rate(&wav).unwrap(), 22050);
+    }
+
+    #[test]
+    fn round_trips_16_bit_mono_pcm() {
+        let result = ChatterboxResult {
+            audio: vec![0.5, -0.5, 0.0],
+            sample_rate: 24000,
+        };
+        let decoded = ChatterboxTTS::wav_to_samples(&result.to_wav_with_format(1, 16)).unwrap();
+        assert_eq!(decoded.len(), 3);
+        assert!((decoded[0] - 0.5).abs() < 0.001);
+        assert!((decoded[1] - (-0.5)).abs() < 0.001);
+    }
+
+    #[test]
+    fn round_trips_24_bit_mono_pcm() {
+        let result = ChatterboxResult {
+            audio: vec![0.25, -0.75],
+            sample_rate: 24000,
+        };
+        let decoded = ChatterboxTTS::wav_to_samples(&result.to_wav_with_format(1, 24)).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert!((decoded[0] - 0.25).abs() < 0.001);
+        assert!((decoded[1] - (-0.75)).abs() < 0.001);
+    }
+
+    #[test]
+    fn round_trips_32_bit_mono_pcm() {
+        let result = ChatterboxResult {
+            audio: vec![0.5, -0.5],
+            sample_rate: 48000,
+        };
+        let decoded = ChatterboxTTS::wav_to_samples(&result.to_wav_with_format(1, 32)).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert!((decoded[0] - 0.5).abs() < 0.001);
+        assert!((decoded[1] - (-0.5)).abs() < 0.001);
+    }
+
+    #[test]
+    fn decodes_32_bit_ieee_float() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&0.25f32.to_le_bytes());
+        data.extend_from_slice(&(-0.75f32).to_le_bytes());
+        let wav = wav_bytes(3, 1, 24000, &data);
+        let decoded = ChatterboxTTS::wav_to_samples(&wav).unwrap();
+        assert_eq!(decoded, vec![0.25, -0.75]);
+    }
+
+    #[test]
+    fn downmixes_stereo_by_averaging_channels() {
+        let result = ChatterboxResult {
+            audio: vec![1.0, -1.0],
+            sample_rate: 24000,
+        };
+        let decoded = ChatterboxTTS::wav_to_samples(&result.to_wav_with_format(2, 16)).unwrap();
+        // Each mono sample was duplicated across both channels, so
+        // averaging them back recovers the original mono value.
+        assert_eq!(decoded.len(), 2);
+        assert!((decoded[0] - 1.0).abs() < 0.001);
+        assert!((decoded[1] - (-1.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn rejects_an_unsupported_bit_depth() {
+        // wav_bytes() always writes a 16-bit fmt chunk for tag != 3, so
+        // force the bits-per-sample field to 8 directly to exercise the
+        // (format_tag, bits_per_sample) combination that decode_sample
+        // doesn't recognize.
+        let mut wav = wav_bytes(1, 1, 24000, &[0u8; 1]);
+        wav[34] = 8;
+        wav[35] = 0;
+        let err = ChatterboxTTS::wav_to_samples(&wav).unwrap_err();
+        assert!(matches!(err, ChatterboxError::InvalidResponse(_)));
+    }
+}
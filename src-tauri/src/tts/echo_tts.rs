@@ -3,7 +3,8 @@
 //! Uses the `echo` crate (Sesame CSM-1B via HuggingFace Candle) for
 //! pure-Rust text-to-speech with streaming audio generation.
 
-use crate::tts::streaming_source::StreamingSource;
+use crate::tts::kokoro::TTSResult;
+use crate::tts::streaming_source::{StreamLoaderController, StreamingSource};
 use crossbeam_channel::bounded;
 use echo::{
     BufferSize, GeneratorConfig, GeneratorService, MaxAudioLength, ModelSource, SpeakerId,
@@ -12,7 +13,9 @@ use echo::{
 use futures_util::StreamExt;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::Mutex as TokioMutex;
+use tracing::Instrument;
 
 /// Errors from the Echo TTS engine.
 #[derive(Debug, thiserror::Error)]
@@ -60,7 +63,9 @@ pub struct EchoTTS {
 impl EchoTTS {
     /// Create and initialize a new EchoTTS instance.
     /// Downloads the CSM-1B model from HuggingFace on first use.
+    #[tracing::instrument(skip_all, fields(elapsed_ms, sample_rate))]
     pub async fn new() -> Result<Self, EchoError> {
+        let started = Instant::now();
         let device = select_device();
 
         let config = GeneratorConfig {
@@ -73,14 +78,17 @@ impl EchoTTS {
             device,
         };
 
-        eprintln!("[Echo] Initializing GeneratorService (this may download the model)...");
+        tracing::info!("initializing GeneratorService (this may download the model)");
 
         let generator = GeneratorService::new(config)
             .await
             .map_err(|e| EchoError::InitError(e.to_string()))?;
 
         let sample_rate = generator.sample_rate().as_u32();
-        eprintln!("[Echo] Initialized, sample_rate={}", sample_rate);
+        let span = tracing::Span::current();
+        span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+        span.record("sample_rate", sample_rate);
+        tracing::info!(sample_rate, "model initialized");
 
         Ok(Self {
             generator,
@@ -112,6 +120,7 @@ impl EchoManager {
     }
 
     /// Initialize the Echo model. Downloads from HuggingFace on first call.
+    #[tracing::instrument(skip_all)]
     pub async fn initialize(&self) -> Result<(), EchoError> {
         let mut guard = self.inner.lock().await;
         if guard.is_none() {
@@ -128,19 +137,22 @@ impl EchoManager {
 
     /// Generate streaming audio for the given text.
     ///
-    /// Returns a `StreamingSource` for immediate playback. The EchoTTS engine
-    /// is temporarily taken from the manager and moved into a background task
-    /// that feeds audio frames to the source. The engine is returned to the
-    /// manager when generation completes.
+    /// Returns a `StreamingSource` for immediate playback, paired with a
+    /// `StreamLoaderController` the caller can use to scrub playback without
+    /// restarting generation. The EchoTTS engine is temporarily taken from
+    /// the manager and moved into a background task that feeds audio frames
+    /// to the source. The engine is returned to the manager when generation
+    /// completes.
     ///
     /// While a stream is active, subsequent calls will return `EchoError::Busy`.
+    #[tracing::instrument(skip(self, text), fields(text_len = text.len(), frame_count))]
     pub async fn generate_streaming(
         &self,
         text: &str,
         speaker_id: u32,
         temperature: f64,
         _speed: f32,
-    ) -> Result<StreamingSource, EchoError> {
+    ) -> Result<(StreamingSource, StreamLoaderController), EchoError> {
         // Take the EchoTTS out of the mutex so it can be moved into the task
         let mut guard = self.inner.lock().await;
         let mut echo = guard.take().ok_or(EchoError::NotInitialized)?;
@@ -160,74 +172,134 @@ impl EchoManager {
         // Spawn background task that owns `echo` and consumes the stream.
         // Both `echo` (generator) and `text_owned` are moved into the task,
         // so the stream's borrows of &mut generator and &str are satisfied.
-        tokio::spawn(async move {
-            eprintln!(
-                "[Echo] Streaming generation started for: \"{}...\"",
-                &text_owned[..text_owned.len().min(50)]
-            );
-
-            let mut total_samples = 0usize;
-
-            // Create the stream inside the task -- it borrows echo.generator and text_owned
-            {
-                eprintln!(
-                    "[Echo] Creating generate_stream for text of {} chars",
-                    text_owned.len()
+        // `.in_current_span()` re-attaches this `generate_streaming` call's
+        // span to the detached task -- `tokio::spawn` otherwise loses it,
+        // since the task is polled independently of its caller.
+        tokio::spawn(
+            async move {
+                let started = Instant::now();
+                tracing::debug!(
+                    preview = &text_owned[..text_owned.len().min(50)],
+                    "streaming generation started"
                 );
-                let mut stream = echo.generator.generate_stream(
-                    &text_owned,
-                    speaker,
-                    max_len,
-                    temp,
-                    top_k,
-                    buffer_size,
-                    None,
-                );
-                eprintln!("[Echo] Stream created, starting to poll frames...");
-
-                while let Some(chunk_result) = stream.next().await {
-                    match chunk_result {
-                        Ok(tensor) => {
-                            let samples: Vec<f32> = match tensor
-                                .to_dtype(candle_core::DType::F32)
-                                .and_then(|t| t.to_vec1())
-                            {
-                                Ok(s) => s,
-                                Err(e) => {
-                                    eprintln!("[Echo] Tensor conversion error: {}", e);
+
+                let mut total_samples = 0usize;
+                let mut frame_count = 0u64;
+
+                // Create the stream inside the task -- it borrows echo.generator and text_owned
+                {
+                    let mut stream = echo.generator.generate_stream(
+                        &text_owned,
+                        speaker,
+                        max_len,
+                        temp,
+                        top_k,
+                        buffer_size,
+                        None,
+                    );
+
+                    while let Some(chunk_result) = stream.next().await {
+                        match chunk_result {
+                            Ok(tensor) => {
+                                let samples: Vec<f32> = match tensor
+                                    .to_dtype(candle_core::DType::F32)
+                                    .and_then(|t| t.to_vec1())
+                                {
+                                    Ok(s) => s,
+                                    Err(e) => {
+                                        tracing::error!(error = %e, "tensor conversion error");
+                                        break;
+                                    }
+                                };
+
+                                total_samples += samples.len();
+                                frame_count += 1;
+
+                                if tx.send(samples).is_err() {
+                                    tracing::warn!("receiver dropped, stopping generation");
                                     break;
                                 }
-                            };
+                            }
+                            Err(e) => {
+                                tracing::error!(error = %e, "stream error");
+                                break;
+                            }
+                        }
+                    }
+                    // stream dropped here, releasing borrows of echo.generator
+                }
+
+                let elapsed_ms = started.elapsed().as_millis() as u64;
+                let audio_secs = total_samples as f64 / sample_rate as f64;
+                tracing::Span::current().record("frame_count", frame_count);
+                tracing::info!(frame_count, elapsed_ms, audio_secs, "generation complete");
+
+                // Return the EchoTTS to the manager so it can be used again
+                let mut guard = inner.lock().await;
+                *guard = Some(echo);
+            }
+            .in_current_span(),
+        );
+
+        let (source, controller) = StreamingSource::seekable(rx, sample_rate);
+        Ok((source, controller))
+    }
+
+    /// Synthesize `text` and return the complete PCM buffer once generation
+    /// finishes, instead of a progressively-filled `StreamingSource`. Used
+    /// where a caller needs the whole clip up front -- e.g. `TtsScheduler`'s
+    /// blocking fallback and its on-disk cache writes.
+    pub async fn generate(
+        &self,
+        text: &str,
+        speaker_id: u32,
+        temperature: f64,
+    ) -> Result<TTSResult, EchoError> {
+        let mut guard = self.inner.lock().await;
+        let mut echo = guard.take().ok_or(EchoError::NotInitialized)?;
+        drop(guard);
 
-                            total_samples += samples.len();
+        let sample_rate = echo.sample_rate;
+        let speaker = SpeakerId::new(speaker_id).unwrap_or_default();
+        let temp = Temperature::new(temperature).unwrap_or_default();
+        let top_k = TopK::default();
+        let buffer_size = BufferSize::new(20).unwrap_or_default();
+        let max_len = MaxAudioLength::new(60000.0).unwrap_or_default();
+
+        let mut audio = Vec::new();
+        let mut error = None;
+        {
+            let mut stream =
+                echo.generator
+                    .generate_stream(text, speaker, max_len, temp, top_k, buffer_size, None);
 
-                            if tx.send(samples).is_err() {
-                                eprintln!("[Echo] Receiver dropped, stopping generation");
+            while let Some(chunk_result) = stream.next().await {
+                match chunk_result {
+                    Ok(tensor) => {
+                        match tensor.to_dtype(candle_core::DType::F32).and_then(|t| t.to_vec1()) {
+                            Ok(samples) => audio.extend::<Vec<f32>>(samples),
+                            Err(e) => {
+                                error = Some(e.to_string());
                                 break;
                             }
                         }
-                        Err(e) => {
-                            eprintln!("[Echo] Stream error: {}", e);
-                            break;
-                        }
+                    }
+                    Err(e) => {
+                        error = Some(e.to_string());
+                        break;
                     }
                 }
-                // stream dropped here, releasing borrows of echo.generator
             }
+        }
 
-            let duration_secs = total_samples as f64 / sample_rate as f64;
-            eprintln!(
-                "[Echo] Generation complete: {} samples ({:.1}s audio)",
-                total_samples, duration_secs
-            );
-
-            // Return the EchoTTS to the manager so it can be used again
-            let mut guard = inner.lock().await;
-            *guard = Some(echo);
-        });
+        let mut guard = self.inner.lock().await;
+        *guard = Some(echo);
+        drop(guard);
 
-        let source = StreamingSource::new(rx, sample_rate);
-        Ok(source)
+        match error {
+            Some(e) => Err(EchoError::GenerationError(e)),
+            None => Ok(TTSResult { audio, sample_rate }),
+        }
     }
 
     /// Get the sample rate of the loaded model.
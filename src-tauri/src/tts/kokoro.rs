@@ -1,30 +1,41 @@
-//! Kokoro TTS engine
+//! Shared TTS result/audio-encoding types.
 //!
-//! This module provides text-to-speech synthesis using the Kokoro-82M model.
-//! Currently uses a placeholder implementation while real ONNX integration is pending.
+//! Originally the Kokoro-82M engine module; the placeholder `KokoroTTS`
+//! engine has been removed (see `EchoManager` for the real engine), but
+//! `TTSResult` and the audiobook-export encoding (`to_wav`/`to_mp3`/`to_ogg`)
+//! are shared by `EchoManager`, `PlaybackManager`, and `export_chapter_audio`.
 
-use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum TTSError {
-    #[error("Failed to initialize ONNX runtime: {0}")]
-    OrtError(String),
-    #[error("Model not found at path: {0}")]
-    ModelNotFound(String),
-    #[error("Failed to generate audio: {0}")]
-    GenerationError(String),
-    #[error("Invalid input: {0}")]
-    InvalidInput(String),
+    #[error("Audio encoding failed: {0}")]
+    EncodeError(String),
 }
 
-/// Voice configuration
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct Voice {
-    pub id: String,
-    pub name: String,
-    pub gender: String,
-    pub accent: String,
+/// Output container for encoded audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    Wav,
+    Mp3,
+    Ogg,
+}
+
+impl Default for AudioFormat {
+    fn default() -> Self {
+        AudioFormat::Wav
+    }
+}
+
+/// Audiobook metadata written as ID3v2 (MP3) or Vorbis comments (OGG).
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct AudioMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub album: Option<String>,
+    pub chapter: Option<String>,
+    pub track: Option<u32>,
 }
 
 /// TTS generation result
@@ -69,120 +80,124 @@ impl TTSResult {
 
         buffer
     }
-}
 
-/// Kokoro TTS engine
-pub struct KokoroTTS {
-    model_dir: PathBuf,
-    sample_rate: u32,
-    initialized: bool,
-}
-
-impl KokoroTTS {
-    pub fn new() -> Self {
-        Self {
-            model_dir: PathBuf::new(),
-            sample_rate: 24000,
-            initialized: false,
+    /// Encode the audio to the requested container, tagging it with the given
+    /// audiobook metadata.
+    pub fn encode(
+        &self,
+        format: AudioFormat,
+        metadata: &AudioMetadata,
+    ) -> Result<Vec<u8>, TTSError> {
+        match format {
+            AudioFormat::Wav => Ok(self.to_wav()),
+            AudioFormat::Mp3 => self.to_mp3(metadata),
+            AudioFormat::Ogg => self.to_ogg(metadata),
         }
     }
 
-    pub fn load_model(&mut self, model_dir: &Path) -> Result<(), TTSError> {
-        let model_path = model_dir.join("model_q8f16.onnx");
-        
-        if !model_path.exists() {
-            return Err(TTSError::ModelNotFound(
-                model_path.to_string_lossy().to_string(),
-            ));
-        }
+    /// Encode to MP3 (LAME) and write ID3v2 tags via lofty.
+    pub fn to_mp3(&self, metadata: &AudioMetadata) -> Result<Vec<u8>, TTSError> {
+        use mp3lame_encoder::{Builder, FlushNoGap, MonoPcm};
+
+        let mut builder = Builder::new()
+            .ok_or_else(|| TTSError::EncodeError("failed to create MP3 encoder".to_string()))?;
+        builder
+            .set_num_channels(1)
+            .map_err(|e| TTSError::EncodeError(e.to_string()))?;
+        builder
+            .set_sample_rate(self.sample_rate)
+            .map_err(|e| TTSError::EncodeError(e.to_string()))?;
+        let mut encoder = builder
+            .build()
+            .map_err(|e| TTSError::EncodeError(e.to_string()))?;
+
+        let pcm: Vec<i16> = self
+            .audio
+            .iter()
+            .map(|s| (s.clamp(-1.0, 1.0) * 32767.0) as i16)
+            .collect();
 
-        self.model_dir = model_dir.to_path_buf();
-        self.initialized = true;
-        
-        // TODO: Real ONNX model loading will be added when kokoros crate is available
-        // or ort API is properly integrated
-        
-        Ok(())
-    }
+        let mut mp3 = Vec::with_capacity(pcm.len());
+        encoder
+            .encode_to_vec(MonoPcm(&pcm), &mut mp3)
+            .map_err(|e| TTSError::EncodeError(e.to_string()))?;
+        encoder
+            .flush_to_vec::<FlushNoGap>(&mut mp3)
+            .map_err(|e| TTSError::EncodeError(e.to_string()))?;
 
-    pub fn is_initialized(&self) -> bool {
-        self.initialized
+        Self::apply_tags(mp3, lofty::file::FileType::Mpeg, metadata)
     }
 
-    /// Generate speech from text
-    /// Currently generates placeholder audio - real Kokoro integration pending
-    pub fn generate(&self, text: &str, _voice_id: &str, speed: f32) -> Result<TTSResult, TTSError> {
-        if text.trim().is_empty() {
-            return Err(TTSError::InvalidInput("Text cannot be empty".to_string()));
+    /// Encode to OGG Vorbis and write Vorbis comments via lofty.
+    pub fn to_ogg(&self, metadata: &AudioMetadata) -> Result<Vec<u8>, TTSError> {
+        use std::io::Cursor;
+        use std::num::{NonZeroU32, NonZeroU8};
+        use vorbis_rs::VorbisEncoderBuilder;
+
+        let mut ogg = Vec::new();
+        {
+            let sample_rate = NonZeroU32::new(self.sample_rate)
+                .ok_or_else(|| TTSError::EncodeError("zero sample rate".to_string()))?;
+            let channels = NonZeroU8::new(1).unwrap();
+            let mut encoder =
+                VorbisEncoderBuilder::new(sample_rate, channels, Cursor::new(&mut ogg))
+                    .and_then(|b| b.build())
+                    .map_err(|e| TTSError::EncodeError(e.to_string()))?;
+
+            encoder
+                .encode_audio_block(&[self.audio.clone()])
+                .map_err(|e| TTSError::EncodeError(e.to_string()))?;
+            encoder
+                .finish()
+                .map_err(|e| TTSError::EncodeError(e.to_string()))?;
         }
 
-        // Placeholder: Generate a gentle tone based on text length
-        // Will be replaced with real Kokoro ONNX inference
-        let duration_seconds = (text.len() as f32 / 15.0) / speed;
-        let num_samples = (duration_seconds * self.sample_rate as f32) as usize;
-
-        let frequency = 440.0;
-        let audio: Vec<f32> = (0..num_samples)
-            .map(|i| {
-                let t = i as f32 / self.sample_rate as f32;
-                let envelope = if t < 0.1 {
-                    t / 0.1
-                } else if t > duration_seconds - 0.1 {
-                    (duration_seconds - t) / 0.1
-                } else {
-                    1.0
-                };
-                (t * frequency * 2.0 * std::f32::consts::PI).sin() * 0.3 * envelope
-            })
-            .collect();
-
-        Ok(TTSResult {
-            audio,
-            sample_rate: self.sample_rate,
-        })
+        Self::apply_tags(ogg, lofty::file::FileType::Vorbis, metadata)
     }
 
-    pub fn split_into_chunks(text: &str, max_chars: usize) -> Vec<String> {
-        let mut chunks = Vec::new();
-        let sentences: Vec<&str> = text.split_inclusive(&['.', '!', '?'][..]).collect();
-
-        let mut current_chunk = String::new();
-
-        for sentence in sentences {
-            if current_chunk.len() + sentence.len() > max_chars && !current_chunk.is_empty() {
-                chunks.push(current_chunk.trim().to_string());
-                current_chunk = sentence.to_string();
-            } else {
-                current_chunk.push_str(sentence);
-            }
+    /// Round-trip encoded bytes through lofty to attach the audiobook tags.
+    fn apply_tags(
+        bytes: Vec<u8>,
+        file_type: lofty::file::FileType,
+        metadata: &AudioMetadata,
+    ) -> Result<Vec<u8>, TTSError> {
+        use lofty::config::WriteOptions;
+        use lofty::file::{AudioFile, TaggedFileExt};
+        use lofty::prelude::*;
+        use lofty::tag::{Tag, TagType};
+        use std::io::Cursor;
+
+        let mut cursor = Cursor::new(bytes);
+        let mut tagged =
+            lofty::read_from(&mut cursor).map_err(|e| TTSError::EncodeError(e.to_string()))?;
+
+        let tag_type = tagged.primary_tag_type();
+        if tagged.primary_tag().is_none() {
+            tagged.insert_tag(Tag::new(tag_type));
         }
+        let tag = tagged
+            .primary_tag_mut()
+            .unwrap_or_else(|| unreachable!("tag just inserted"));
 
-        if !current_chunk.trim().is_empty() {
-            chunks.push(current_chunk.trim().to_string());
+        if let Some(title) = metadata.chapter.as_ref().or(metadata.title.as_ref()) {
+            tag.set_title(title.clone());
         }
+        if let Some(author) = &metadata.author {
+            tag.set_artist(author.clone());
+        }
+        if let Some(album) = metadata.album.as_ref().or(metadata.title.as_ref()) {
+            tag.set_album(album.clone());
+        }
+        if let Some(track) = metadata.track {
+            tag.set_track(track);
+        }
+        let _ = (file_type, TagType::Id3v2); // formats selected by lofty from the stream
 
-        chunks
-    }
-
-    pub fn get_voices() -> Vec<Voice> {
-        vec![
-            Voice { id: "af_heart".to_string(), name: "Heart".to_string(), gender: "female".to_string(), accent: "american".to_string() },
-            Voice { id: "af_bella".to_string(), name: "Bella".to_string(), gender: "female".to_string(), accent: "american".to_string() },
-            Voice { id: "af_nova".to_string(), name: "Nova".to_string(), gender: "female".to_string(), accent: "american".to_string() },
-            Voice { id: "af_sky".to_string(), name: "Sky".to_string(), gender: "female".to_string(), accent: "american".to_string() },
-            Voice { id: "am_adam".to_string(), name: "Adam".to_string(), gender: "male".to_string(), accent: "american".to_string() },
-            Voice { id: "am_echo".to_string(), name: "Echo".to_string(), gender: "male".to_string(), accent: "american".to_string() },
-            Voice { id: "am_michael".to_string(), name: "Michael".to_string(), gender: "male".to_string(), accent: "american".to_string() },
-            Voice { id: "bf_alice".to_string(), name: "Alice".to_string(), gender: "female".to_string(), accent: "british".to_string() },
-            Voice { id: "bf_emma".to_string(), name: "Emma".to_string(), gender: "female".to_string(), accent: "british".to_string() },
-            Voice { id: "bm_daniel".to_string(), name: "Daniel".to_string(), gender: "male".to_string(), accent: "british".to_string() },
-            Voice { id: "bm_george".to_string(), name: "George".to_string(), gender: "male".to_string(), accent: "british".to_string() },
-        ]
-    }
-}
+        cursor.set_position(0);
+        tagged
+            .save_to(&mut cursor, WriteOptions::default())
+            .map_err(|e| TTSError::EncodeError(e.to_string()))?;
 
-impl Default for KokoroTTS {
-    fn default() -> Self {
-        Self::new()
+        Ok(cursor.into_inner())
     }
 }
@@ -0,0 +1,98 @@
+//! OS media-key / MPRIS integration.
+//!
+//! Wires `souvlaki`'s `MediaControls` to a [`PlaybackManager`], mirroring
+//! muss's `SystemControlWrapper`: hardware play/pause/next/previous keys and
+//! the Linux MPRIS bus / macOS Now Playing widget can drive the reader, and
+//! every `tts-playback-event` we emit is mirrored back out as OS-level
+//! metadata and playback-state updates. Runs entirely off callbacks (souvlaki's
+//! own D-Bus/Media Remote thread plus the existing Tauri event loop), so it
+//! never blocks the audio thread.
+
+use crate::tts::{PlaybackManager, TtsPlaybackEvent};
+use souvlaki::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Listener};
+
+/// Owns the OS-side media control surface for the lifetime of the app.
+///
+/// Dropping this tears down the MPRIS/Now Playing registration, so it's kept
+/// alongside the [`PlaybackManager`] it was built from (see `AppState`).
+pub struct MediaControlsBridge {
+    controls: Arc<Mutex<MediaControls>>,
+}
+
+impl MediaControlsBridge {
+    /// Register OS media controls and wire them to `manager`.
+    ///
+    /// OS `Play`/`Pause`/`Toggle`/`Stop`/`Next`/`Previous` events are mapped
+    /// to the corresponding `PlaybackCmd`s; `Next`/`Previous` reuse the seek
+    /// machinery to jump to the adjacent queued chunk's boundary. We also
+    /// listen for `tts-playback-event` so the OS side's title, chunk-as-track
+    /// metadata, and play/pause state stay in sync without polling.
+    pub fn new(app: &AppHandle, manager: PlaybackManager) -> Result<Self, String> {
+        let config = PlatformConfig {
+            dbus_name: "kokoro_reader",
+            display_name: "Kokoro Reader",
+            hwnd: None,
+        };
+        let mut raw = MediaControls::new(config).map_err(|e| format!("{:?}", e))?;
+
+        let cmd_manager = manager.clone();
+        raw.attach(move |event| match event {
+            MediaControlEvent::Play => cmd_manager.resume(),
+            MediaControlEvent::Pause => cmd_manager.pause(),
+            MediaControlEvent::Toggle => {
+                if cmd_manager.is_paused() {
+                    cmd_manager.resume();
+                } else {
+                    cmd_manager.pause();
+                }
+            }
+            MediaControlEvent::Stop => cmd_manager.stop(),
+            MediaControlEvent::Next => cmd_manager.next_chunk(),
+            MediaControlEvent::Previous => cmd_manager.previous_chunk(),
+            _ => {}
+        })
+        .map_err(|e| format!("{:?}", e))?;
+
+        let controls = Arc::new(Mutex::new(raw));
+        let controls_for_events = Arc::clone(&controls);
+        let status_manager = manager;
+        app.listen("tts-playback-event", move |event| {
+            let Ok(payload) = serde_json::from_str::<TtsPlaybackEvent>(event.payload()) else {
+                return;
+            };
+            let Ok(mut controls) = controls_for_events.lock() else {
+                return;
+            };
+            sync_controls(&mut controls, &status_manager, &payload);
+        });
+
+        Ok(Self { controls })
+    }
+}
+
+/// Push the current session title, chunk-as-track-position, and play/pause
+/// state to the OS. Called on every `tts-playback-event` rather than only on
+/// transitions, since souvlaki has no "just update position" call separate
+/// from `set_playback`.
+fn sync_controls(
+    controls: &mut MediaControls,
+    manager: &PlaybackManager,
+    payload: &TtsPlaybackEvent,
+) {
+    let _ = controls.set_metadata(MediaMetadata {
+        title: Some(&payload.session_id),
+        artist: Some(&format!("Chunk {}", payload.chunk_index + 1)),
+        ..Default::default()
+    });
+
+    let playback = if manager.is_paused() {
+        MediaPlayback::Paused { progress: None }
+    } else if manager.is_playing() {
+        MediaPlayback::Playing { progress: None }
+    } else {
+        MediaPlayback::Stopped
+    };
+    let _ = controls.set_playback(playback);
+}
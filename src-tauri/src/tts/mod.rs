@@ -1,23 +1,65 @@
 //! TTS module for text-to-speech engines
 
 mod audio;
+mod audio_backend;
 mod chatterbox;
+mod echo_tts;
+mod kokoro;
+mod media_controls;
+mod model_manifest;
 mod playback;
+mod recording;
+mod resample;
+mod scheduler;
+mod segment;
+mod spatial;
+mod streaming_source;
+mod system_tts;
+mod timeline;
 
-pub use audio::AudioPlayer;
-pub use chatterbox::{ChatterboxManager, ChatterboxError};
-pub use playback::{PlaybackManager, TtsPlaybackEvent};
+pub use audio::{play_wav_blocking, AudioError};
+pub use audio_backend::{list_devices, AudioBackend, BACKENDS};
+pub use chatterbox::{
+    CancellationToken, ChatterboxError, ChatterboxManager, ReferenceAudio, SynthOptions,
+};
+pub use echo_tts::{EchoError, EchoManager};
+pub use kokoro::{AudioFormat, AudioMetadata, TTSResult};
+pub use media_controls::MediaControlsBridge;
+pub use model_manifest::{
+    cache_dir as echo_model_cache_dir, download_missing as download_echo_model_files,
+    fetch_manifest as fetch_echo_model_manifest, missing_files as echo_model_missing_files,
+    total_size as echo_model_download_size, ManifestEntry as EchoModelFile,
+    ManifestError as ModelManifestError, ManifestProgress as EchoModelDownloadProgress,
+};
+pub use playback::{
+    PlaybackLifecycleStatus, PlaybackManager, TtsPlaybackEvent, TtsPlaybackStatusEvent,
+};
+pub use resample::ResampledSource;
+pub use scheduler::{SynthesisParams, TtsScheduler};
+pub use segment::{SegmentInfo, SegmentWriter};
+pub use spatial::{SpatialConfig, SpatialError};
+pub use streaming_source::{
+    JitterBufferStats, LoaderCommand, StreamLoaderController, StreamingSource,
+};
+pub use system_tts::{SystemError, SystemManager};
+pub use timeline::Timeline;
 
 /// Available TTS engines
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum TTSEngine {
     Chatterbox,
     Qwen3TTS,
+    /// Echo-1B, this app's primary native Rust engine. Requires a ~4GB
+    /// model download before first use.
+    Echo,
+    /// The OS's own voice (SAPI/WinRT, AVSpeechSynthesizer, Speech
+    /// Dispatcher). No download, so it's the instant-on default.
+    System,
 }
 
 impl Default for TTSEngine {
     fn default() -> Self {
-        TTSEngine::Qwen3TTS
+        TTSEngine::System
     }
 }
 
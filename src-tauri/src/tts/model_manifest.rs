@@ -0,0 +1,424 @@
+//! Real per-file manifest for the Echo (`sesame/csm-1b`) HuggingFace model.
+//!
+//! `echo::GeneratorService::new()` downloads the model itself through an
+//! opaque internal client with no progress hook, so `check_model_status` and
+//! `download_model` can't observe *that* download directly. Instead, this
+//! module fetches the repo's real file listing (names + sizes) from
+//! HuggingFace's lightweight listing API and, when asked to download,
+//! streams each file itself into the same cache directory `echo` reads from
+//! -- giving honest per-file progress up front, even though `echo`'s own
+//! fetch afterwards may still re-request a file if it resolves to a
+//! differently-named revision than the `main` snapshot used here.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+use thiserror::Error;
+
+/// The HuggingFace repo backing the Echo TTS engine.
+pub const MODEL_ID: &str = "sesame/csm-1b";
+
+/// Default mirror when no `model_source` override is configured.
+const DEFAULT_BASE_URL: &str = "https://huggingface.co";
+
+/// Number of times a single file is retried (resuming from its partial
+/// `.tmp`) before `download_missing` gives up on it.
+const MAX_RETRIES: usize = 3;
+
+#[derive(Error, Debug)]
+pub enum ManifestError {
+    #[error("Network error: {0}")]
+    NetworkError(String),
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+/// One file in the repo, with its real size when the server reports one.
+#[derive(Debug, Clone)]
+pub struct ManifestEntry {
+    pub rfilename: String,
+    pub size: Option<u64>,
+}
+
+/// Per-file download progress, emitted once per file and periodically while
+/// streaming its body.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ManifestProgress {
+    pub file_name: String,
+    pub bytes_downloaded: u64,
+    pub total_bytes: Option<u64>,
+    pub current_file: usize,
+    pub total_files: usize,
+    pub status: String,
+    /// Base URL of the mirror this file is being fetched from, when known.
+    #[serde(default)]
+    pub mirror: Option<String>,
+}
+
+/// The local HuggingFace cache directory for `MODEL_ID`
+/// (`~/.cache/huggingface/hub/models--sesame--csm-1b`).
+pub fn cache_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".cache")
+        .join("huggingface")
+        .join("hub")
+        .join("models--sesame--csm-1b")
+}
+
+/// The snapshot directory files actually live under, if the repo has been
+/// fetched at least once. `echo`'s own client names this after the resolved
+/// commit sha; any snapshot directory present is a full copy of the repo at
+/// some revision, so the first one found is enough to check file presence.
+fn existing_snapshot_dir() -> Option<PathBuf> {
+    let snapshots = cache_dir().join("snapshots");
+    fs::read_dir(&snapshots)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| path.is_dir())
+}
+
+/// Fetch the repo's real file list from HuggingFace's model-info API, then
+/// `HEAD` each file to learn its real size.
+pub fn fetch_manifest() -> Result<Vec<ManifestEntry>, ManifestError> {
+    #[derive(serde::Deserialize)]
+    struct Sibling {
+        rfilename: String,
+    }
+    #[derive(serde::Deserialize)]
+    struct RepoInfo {
+        siblings: Vec<Sibling>,
+    }
+
+    let url = format!("https://huggingface.co/api/models/{}", MODEL_ID);
+    let body = ureq::get(&url)
+        .call()
+        .map_err(|e| ManifestError::NetworkError(e.to_string()))?
+        .into_body()
+        .read_to_string()
+        .map_err(|e| ManifestError::NetworkError(e.to_string()))?;
+    let info: RepoInfo =
+        serde_json::from_str(&body).map_err(|e| ManifestError::NetworkError(e.to_string()))?;
+
+    Ok(info
+        .siblings
+        .into_iter()
+        .map(|sibling| {
+            let size = file_content_length(&sibling.rfilename);
+            ManifestEntry {
+                rfilename: sibling.rfilename,
+                size,
+            }
+        })
+        .collect())
+}
+
+/// `HEAD` a file's resolve URL to learn its size without downloading it.
+fn file_content_length(rfilename: &str) -> Option<u64> {
+    let url = format!(
+        "https://huggingface.co/{}/resolve/main/{}",
+        MODEL_ID, rfilename
+    );
+    ureq::head(&url)
+        .call()
+        .ok()?
+        .headers()
+        .get("content-length")?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Entries from `manifest` not yet present in the local cache snapshot.
+pub fn missing_files(manifest: &[ManifestEntry]) -> Vec<ManifestEntry> {
+    match existing_snapshot_dir() {
+        None => manifest.to_vec(),
+        Some(dir) => manifest
+            .iter()
+            .filter(|entry| !dir.join(&entry.rfilename).exists())
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Sum of `size` across `files`, treating an unreported size as 0 rather
+/// than guessing at one.
+pub fn total_size(files: &[ManifestEntry]) -> u64 {
+    files.iter().filter_map(|file| file.size).sum()
+}
+
+/// Download every file in `missing` into the cache's `main` snapshot
+/// directory, reporting progress per file via `on_progress`. `echo`'s own
+/// initialization, run afterward, finds these files already present when it
+/// resolves to the same revision, and simply re-fetches them otherwise.
+///
+/// `mirrors` is tried in order for each file, falling back to the default
+/// HuggingFace URL when empty, for users behind firewalls or in regions
+/// where HuggingFace is slow or blocked. A dropped connection mid-file is
+/// retried (up to `MAX_RETRIES` times) by resuming from the partial `.tmp`
+/// already on disk via a `Range` request, rather than restarting the file
+/// from zero.
+pub fn download_missing(
+    missing: &[ManifestEntry],
+    mirrors: &[String],
+    on_progress: &dyn Fn(ManifestProgress),
+) -> Result<(), ManifestError> {
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let base_urls: Vec<&str> = if mirrors.is_empty() {
+        vec![DEFAULT_BASE_URL]
+    } else {
+        mirrors.iter().map(String::as_str).collect()
+    };
+
+    let dest_dir = cache_dir().join("snapshots").join("main");
+    fs::create_dir_all(&dest_dir)?;
+
+    let total_files = missing.len();
+    for (index, entry) in missing.iter().enumerate() {
+        let dest_path = dest_dir.join(&entry.rfilename);
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut attempt = 0;
+        let mirror_used = loop {
+            match download_file(
+                entry,
+                &dest_path,
+                index + 1,
+                total_files,
+                &base_urls,
+                on_progress,
+            ) {
+                Ok(mirror) => break mirror,
+                Err(ManifestError::NetworkError(msg)) if attempt < MAX_RETRIES => {
+                    attempt += 1;
+                    eprintln!(
+                        "[Echo] {} failed (attempt {}/{}): {} -- resuming",
+                        entry.rfilename, attempt, MAX_RETRIES, msg
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        on_progress(ManifestProgress {
+            file_name: entry.rfilename.clone(),
+            bytes_downloaded: 0,
+            total_bytes: entry.size,
+            current_file: index + 1,
+            total_files,
+            status: "file_complete".to_string(),
+            mirror: Some(mirror_used),
+        });
+    }
+
+    Ok(())
+}
+
+/// Download one file, trying each mirror in `base_urls` in order until one
+/// succeeds. A `NetworkError` is only surfaced once every mirror has failed.
+/// Returns the base URL of the mirror that actually served the file.
+fn download_file(
+    entry: &ManifestEntry,
+    dest_path: &std::path::Path,
+    current_file: usize,
+    total_files: usize,
+    base_urls: &[&str],
+    on_progress: &dyn Fn(ManifestProgress),
+) -> Result<String, ManifestError> {
+    let mut last_err = ManifestError::NetworkError("no mirrors configured".to_string());
+    for base_url in base_urls {
+        let url = format!(
+            "{}/{}/resolve/main/{}",
+            base_url.trim_end_matches('/'),
+            MODEL_ID,
+            entry.rfilename
+        );
+        match download_from_mirror(
+            &url,
+            base_url,
+            entry,
+            dest_path,
+            current_file,
+            total_files,
+            on_progress,
+        ) {
+            Ok(()) => return Ok(base_url.to_string()),
+            Err(ManifestError::NetworkError(msg)) => {
+                eprintln!(
+                    "[Echo] mirror {} failed for {}: {}",
+                    base_url, entry.rfilename, msg
+                );
+                last_err = ManifestError::NetworkError(msg);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Err(last_err)
+}
+
+/// Decide whether a response is actually continuing a previous download, and
+/// compute the true total file size from it. A 206 means the server honored
+/// our `Range` request and we keep appending; any other status (typically
+/// 200) means the range was ignored, so the download restarts from scratch.
+/// `content-length` on a 206 is just the *remaining* bytes, so it needs the
+/// partial size already on disk added back for accurate total-progress
+/// reporting; on a fresh download it's used as-is, falling back to the
+/// manifest's own size if the server didn't send one.
+fn resume_plan(
+    partial_bytes: u64,
+    status: u16,
+    remaining_bytes: Option<u64>,
+    manifest_size: Option<u64>,
+) -> (bool, Option<u64>) {
+    let resumed = partial_bytes > 0 && status == 206;
+    let total_bytes = match (resumed, remaining_bytes) {
+        (true, Some(remaining)) => Some(remaining + partial_bytes),
+        (_, other) => other.or(manifest_size),
+    };
+    (resumed, total_bytes)
+}
+
+/// Fetch `entry` from one specific mirror, resuming from a partial `.tmp`
+/// via a `Range` request when one is already on disk.
+fn download_from_mirror(
+    url: &str,
+    mirror: &str,
+    entry: &ManifestEntry,
+    dest_path: &std::path::Path,
+    current_file: usize,
+    total_files: usize,
+    on_progress: &dyn Fn(ManifestProgress),
+) -> Result<(), ManifestError> {
+    let temp_path = dest_path.with_extension("tmp");
+    let partial_bytes = fs::metadata(&temp_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = ureq::get(url);
+    if partial_bytes > 0 {
+        request = request.header("Range", &format!("bytes={}-", partial_bytes));
+        on_progress(ManifestProgress {
+            file_name: entry.rfilename.clone(),
+            bytes_downloaded: partial_bytes,
+            total_bytes: entry.size,
+            current_file,
+            total_files,
+            status: "resuming".to_string(),
+            mirror: Some(mirror.to_string()),
+        });
+    }
+
+    let response = request
+        .call()
+        .map_err(|e| ManifestError::NetworkError(e.to_string()))?;
+
+    let remaining_bytes = response
+        .headers()
+        .get("content-length")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok());
+    let (resumed, total_bytes) = resume_plan(
+        partial_bytes,
+        response.status().as_u16(),
+        remaining_bytes,
+        entry.size,
+    );
+
+    let (file, mut bytes_downloaded) = if resumed {
+        (
+            OpenOptions::new().append(true).open(&temp_path)?,
+            partial_bytes,
+        )
+    } else {
+        (File::create(&temp_path)?, 0)
+    };
+    let mut writer = BufWriter::new(file);
+
+    let mut reader = response.into_body().into_reader();
+    let mut buffer = [0u8; 65536];
+    let mut last_update = Instant::now();
+
+    loop {
+        // A read failure mid-body is a dropped connection -- surface it as a
+        // NetworkError so the retry loop in `download_missing` resumes from
+        // the partial `.tmp` instead of restarting the whole file.
+        let bytes_read = match reader.read(&mut buffer) {
+            Ok(n) => n,
+            Err(e) => {
+                writer.flush()?;
+                return Err(ManifestError::NetworkError(e.to_string()));
+            }
+        };
+        if bytes_read == 0 {
+            break;
+        }
+        writer.write_all(&buffer[..bytes_read])?;
+        bytes_downloaded += bytes_read as u64;
+
+        if last_update.elapsed().as_millis() >= 100 {
+            on_progress(ManifestProgress {
+                file_name: entry.rfilename.clone(),
+                bytes_downloaded,
+                total_bytes,
+                current_file,
+                total_files,
+                status: "downloading".to_string(),
+                mirror: Some(mirror.to_string()),
+            });
+            last_update = Instant::now();
+        }
+    }
+
+    writer.flush()?;
+    drop(writer);
+    fs::rename(&temp_path, dest_path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod resume_plan_tests {
+    use super::*;
+
+    #[test]
+    fn fresh_download_uses_the_response_content_length() {
+        let (resumed, total) = resume_plan(0, 200, Some(1000), Some(2000));
+        assert!(!resumed);
+        assert_eq!(total, Some(1000));
+    }
+
+    #[test]
+    fn fresh_download_falls_back_to_the_manifest_size_with_no_content_length() {
+        let (resumed, total) = resume_plan(0, 200, None, Some(2000));
+        assert!(!resumed);
+        assert_eq!(total, Some(2000));
+    }
+
+    #[test]
+    fn honored_range_request_adds_back_the_partial_bytes() {
+        let (resumed, total) = resume_plan(500, 206, Some(1500), Some(2000));
+        assert!(resumed);
+        assert_eq!(total, Some(2000));
+    }
+
+    #[test]
+    fn server_ignoring_the_range_request_restarts_from_scratch() {
+        // Server replied 200 instead of 206 -- our Range header was ignored,
+        // so this isn't a resume even though we had partial bytes on disk.
+        let (resumed, total) = resume_plan(500, 200, Some(2000), Some(2000));
+        assert!(!resumed);
+        assert_eq!(total, Some(2000));
+    }
+
+    #[test]
+    fn no_partial_bytes_is_never_a_resume_even_on_206() {
+        let (resumed, _total) = resume_plan(0, 206, Some(1000), Some(1000));
+        assert!(!resumed);
+    }
+}
@@ -3,17 +3,23 @@
 //! Runs a dedicated audio thread that owns rodio OutputStream/Sink and can
 //! play queued WAV chunks sequentially with gapless transitions.
 
-use rodio::{Decoder, OutputStream, Sink, Source};
-use serde::Serialize;
+use crate::tts::audio_backend::{self, AudioBackend};
+use crate::tts::kokoro::AudioFormat;
+use crate::tts::recording::RecordingWriter;
+use crate::tts::spatial::SpatialConfig;
+use crate::tts::streaming_source::StreamingSource;
+use crate::tts::timeline::Timeline;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use std::io::Cursor;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::Instant;
 use std::sync::{mpsc, Arc};
 use std::thread;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TtsPlaybackEvent {
     pub session_id: String,
     pub chunk_index: usize,
@@ -21,6 +27,37 @@ pub struct TtsPlaybackEvent {
     pub message: Option<String>,
 }
 
+/// Coarse, typed playback lifecycle signal, emitted on the `tts-playback-status`
+/// event alongside the detailed `tts-playback-event` stream above.
+///
+/// `TtsPlaybackEvent` carries a much finer-grained, string-tagged feed
+/// (`buffering`, `chunk_queued`, `word_highlight`, `seeked`, ...) that the
+/// frontend matches on by string today. This enum exists so callers that only
+/// care about the high-level state machine -- is something playing, paused,
+/// or done -- don't have to keep a copy of that string vocabulary in sync;
+/// it's a typed, authoritative replacement for polling `is_playing`/`is_paused`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PlaybackLifecycleStatus {
+    /// The first chunk of a session started playing.
+    Started,
+    /// Playback moved on to the chunk at `index`.
+    ChunkPlaying { index: usize },
+    Paused,
+    Resumed,
+    /// The session was stopped via `stop`. A queue that runs dry does not
+    /// emit this.
+    Finished,
+    Error { message: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtsPlaybackStatusEvent {
+    pub session_id: String,
+    #[serde(flatten)]
+    pub status: PlaybackLifecycleStatus,
+}
+
 #[derive(Debug, Clone)]
 struct QueuedChunk {
     session_id: String,
@@ -29,13 +66,64 @@ struct QueuedChunk {
     speed: f32,
 }
 
+/// A chunk whose audio arrives progressively (e.g. the Python streaming
+/// bridge) rather than as a complete WAV buffer up front.
+struct QueuedStream {
+    session_id: String,
+    chunk_index: usize,
+    source: StreamingSource,
+    speed: f32,
+    /// Word-level schedule for karaoke highlighting, if the caller supplied
+    /// the chunk's words (e.g. from the chapter's `Vec<Word>`).
+    timeline: Option<Timeline>,
+}
+
+impl std::fmt::Debug for QueuedStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueuedStream")
+            .field("session_id", &self.session_id)
+            .field("chunk_index", &self.chunk_index)
+            .field("speed", &self.speed)
+            .field("has_timeline", &self.timeline.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug)]
 enum PlaybackCmd {
     StartSession { session_id: String },
     EnqueueWav(QueuedChunk),
+    EnqueueStream(QueuedStream),
     Stop,
     Pause,
     Resume,
+    /// Seek to a global playback time across all queued chunks.
+    Seek(Duration),
+    /// Jump to the start of the next queued chunk (media-key "Next").
+    Next,
+    /// Jump to the start of the previous queued chunk (media-key "Previous").
+    Previous,
+    /// Switch the output backend (e.g. "rodio", "pipe", "subprocess") and
+    /// optionally target a specific device/path/command.
+    SetBackend {
+        name: String,
+        device: Option<String>,
+    },
+    /// Begin mirroring every appended chunk's audio to a single file.
+    StartRecording { path: PathBuf, format: AudioFormat },
+    /// Stop recording, encode what was captured, and emit
+    /// `recording_finished` with the final path.
+    StopRecording,
+    /// Enable (or, if `None`, disable) HRTF binaural rendering of chunks
+    /// appended from now on.
+    SetSpatial(Option<SpatialConfig>),
+}
+
+/// A chunk buffered in `pending_by_index`, waiting for its turn to be
+/// appended to the backend in order.
+enum PendingChunk {
+    Wav(Vec<u8>, f32),
+    Stream(StreamingSource, f32, Option<Timeline>),
 }
 
 #[derive(Debug, Default)]
@@ -46,6 +134,8 @@ pub struct PlaybackStatus {
     pub queued_count: AtomicUsize,
     /// Index of the chunk currently playing
     pub current_chunk: AtomicUsize,
+    /// Global elapsed playback position, in milliseconds.
+    pub position_ms: AtomicU64,
 }
 
 /// Manages a background audio thread and a queue of chunks.
@@ -53,6 +143,7 @@ pub struct PlaybackStatus {
 /// Uses a persistent Sink for gapless playback - chunks are appended
 /// sequentially and play without gaps.
 pub struct PlaybackManager {
+    app: AppHandle,
     tx: mpsc::Sender<PlaybackCmd>,
     status: Arc<PlaybackStatus>,
     pub current_session_id: Arc<std::sync::Mutex<Option<String>>>,
@@ -61,6 +152,7 @@ pub struct PlaybackManager {
 impl Clone for PlaybackManager {
     fn clone(&self) -> Self {
         Self {
+            app: self.app.clone(),
             tx: self.tx.clone(),
             status: Arc::clone(&self.status),
             current_session_id: Arc::clone(&self.current_session_id),
@@ -75,9 +167,11 @@ impl PlaybackManager {
         let current_session_id = Arc::new(std::sync::Mutex::new(None));
 
         let status_for_thread = Arc::clone(&status);
-        thread::spawn(move || audio_thread_main(app, rx, status_for_thread));
+        let app_for_thread = app.clone();
+        thread::spawn(move || audio_thread_main(app_for_thread, rx, status_for_thread));
 
         Self {
+            app,
             tx,
             status,
             current_session_id,
@@ -107,6 +201,28 @@ impl PlaybackManager {
         }));
     }
 
+    /// Enqueue a chunk whose audio arrives progressively as a `StreamingSource`
+    /// (e.g. `EchoManager::generate_streaming`) instead of a complete WAV.
+    /// Ordering against WAV chunks from the same session is by `chunk_index`,
+    /// same as `enqueue_wav`. `timeline`, if given, drives `word_highlight`
+    /// events as this chunk plays.
+    pub fn enqueue_stream(
+        &self,
+        session_id: String,
+        chunk_index: usize,
+        source: StreamingSource,
+        speed: f32,
+        timeline: Option<Timeline>,
+    ) {
+        let _ = self.tx.send(PlaybackCmd::EnqueueStream(QueuedStream {
+            session_id,
+            chunk_index,
+            source,
+            speed,
+            timeline,
+        }));
+    }
+
     pub fn stop(&self) {
         // clear session id
         if let Ok(mut id) = self.current_session_id.lock() {
@@ -123,6 +239,55 @@ impl PlaybackManager {
         let _ = self.tx.send(PlaybackCmd::Resume);
     }
 
+    /// Seek to a global playback time measured across all queued chunks.
+    pub fn seek(&self, position: Duration) {
+        let _ = self.tx.send(PlaybackCmd::Seek(position));
+    }
+
+    /// Jump to the start of the next queued chunk (media-key "Next").
+    pub fn next_chunk(&self) {
+        let _ = self.tx.send(PlaybackCmd::Next);
+    }
+
+    /// Jump to the start of the previous queued chunk (media-key "Previous").
+    pub fn previous_chunk(&self) {
+        let _ = self.tx.send(PlaybackCmd::Previous);
+    }
+
+    /// Current global playback position.
+    pub fn position(&self) -> Duration {
+        Duration::from_millis(self.status.position_ms.load(Ordering::SeqCst))
+    }
+
+    /// Switch the audio output backend and optionally select a device.
+    pub fn set_backend(&self, name: String, device: Option<String>) {
+        let _ = self.tx.send(PlaybackCmd::SetBackend { name, device });
+    }
+
+    /// Enumerate available output device names (cpal).
+    pub fn list_devices(&self) -> Vec<String> {
+        crate::tts::audio_backend::list_devices()
+    }
+
+    /// Enable (or, if `None`, disable) HRTF binaural rendering at the given
+    /// direction. Applies to chunks appended from now on; chunks already
+    /// queued in the backend keep playing as they were appended.
+    pub fn set_spatial(&self, config: Option<SpatialConfig>) {
+        let _ = self.tx.send(PlaybackCmd::SetSpatial(config));
+    }
+
+    /// Start mirroring every appended chunk's audio to a single file at
+    /// `path`, encoded as `format`. Runs alongside normal playback.
+    pub fn start_recording(&self, path: PathBuf, format: AudioFormat) {
+        let _ = self.tx.send(PlaybackCmd::StartRecording { path, format });
+    }
+
+    /// Stop recording and encode what was captured so far. Emits
+    /// `recording_finished` (or `error` on failure) with the final path.
+    pub fn stop_recording(&self) {
+        let _ = self.tx.send(PlaybackCmd::StopRecording);
+    }
+
     pub fn is_playing(&self) -> bool {
         self.status.is_playing.load(Ordering::SeqCst)
     }
@@ -136,6 +301,50 @@ fn emit_event(app: &AppHandle, payload: TtsPlaybackEvent) {
     let _ = app.emit("tts-playback-event", payload);
 }
 
+fn emit_status(app: &AppHandle, session_id: &str, status: PlaybackLifecycleStatus) {
+    tracing::info!(session_id, status = ?status, "playback status transition");
+    let _ = app.emit(
+        "tts-playback-status",
+        TtsPlaybackStatusEvent {
+            session_id: session_id.to_string(),
+            status,
+        },
+    );
+}
+
+/// Finalize an in-flight recording, if any, encoding and writing what was
+/// captured and emitting `recording_finished` (or `error` on failure).
+fn finish_recording(
+    recording: &mut Option<RecordingWriter>,
+    app: &AppHandle,
+    session_id: &Option<String>,
+    chunk_index: usize,
+) {
+    let Some(writer) = recording.take() else {
+        return;
+    };
+    match writer.finish() {
+        Ok(path) => emit_event(
+            app,
+            TtsPlaybackEvent {
+                session_id: session_id.clone().unwrap_or_default(),
+                chunk_index,
+                event: "recording_finished".to_string(),
+                message: Some(path.to_string_lossy().into_owned()),
+            },
+        ),
+        Err(e) => emit_event(
+            app,
+            TtsPlaybackEvent {
+                session_id: session_id.clone().unwrap_or_default(),
+                chunk_index,
+                event: "error".to_string(),
+                message: Some(format!("Failed to write recording: {}", e)),
+            },
+        ),
+    }
+}
+
 fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Arc<PlaybackStatus>) {
     let mut active_session: Option<String> = None;
 
@@ -148,30 +357,54 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
     // Track which chunk is currently playing (for events)
     let mut current_playing_chunk: usize = 0;
 
-    // WAVs that arrived out of order, waiting for their turn
-    let mut pending_by_index: BTreeMap<usize, (Vec<u8>, f32)> = BTreeMap::new();
+    // Chunks that arrived out of order, waiting for their turn
+    let mut pending_by_index: BTreeMap<usize, PendingChunk> = BTreeMap::new();
 
-    // Create the output stream once for the lifetime of the thread
-    let (_stream, stream_handle) = match OutputStream::try_default() {
-        Ok(v) => v,
-        Err(e) => {
-            emit_event(
-                &app,
-                TtsPlaybackEvent {
-                    session_id: "".to_string(),
-                    chunk_index: 0,
-                    event: "error".to_string(),
-                    message: Some(format!("Failed to create audio output stream: {}", e)),
-                },
-            );
-            return;
-        }
-    };
+    // Ordered record of every chunk appended this session, with its decoded
+    // duration. Kept so we can rebuild the output when seeking.
+    let mut appended: Vec<(Vec<u8>, f32)> = Vec::new();
+    let mut chunk_durations: Vec<Duration> = Vec::new();
+
+    // Position tracking: sum of fully-played chunk durations plus the elapsed
+    // time within the chunk currently playing.
+    let mut completed_duration = Duration::ZERO;
+    let mut chunk_started_at: Option<Instant> = None;
+    let mut last_position_emit = Instant::now();
+
+    // Active whole-session recording, if `StartRecording` was sent. Fed from
+    // the same in-order append loop that queues chunks to the sink. WAV
+    // chunks are captured as played; streamed chunks are not (see below).
+    let mut recording: Option<RecordingWriter> = None;
 
-    // Persistent sink for the current session - enables gapless playback
-    let mut session_sink: Option<Sink> = None;
+    // Word-level schedule for the currently-playing streamed chunk, plus a
+    // handle into its live sample counter, used to emit `word_highlight`
+    // events as playback advances.
+    let mut active_timeline: Option<(Timeline, Arc<AtomicUsize>)> = None;
+    let mut last_word_index: Option<usize> = None;
 
-    // Track the last known "len" of the sink to detect when chunks finish
+    // Construct the default backend once for the lifetime of the thread. It can
+    // be swapped at runtime via PlaybackCmd::SetBackend.
+    let mut backend: Box<dyn AudioBackend> =
+        match (audio_backend::builder_for("rodio"))(None) {
+            Ok(b) => b,
+            Err(e) => {
+                emit_event(
+                    &app,
+                    TtsPlaybackEvent {
+                        session_id: "".to_string(),
+                        chunk_index: 0,
+                        event: "error".to_string(),
+                        message: Some(format!("Failed to create audio output stream: {}", e)),
+                    },
+                );
+                return;
+            }
+        };
+
+    // Whether the backend has an initialized output for the current session.
+    let mut session_active = false;
+
+    // Track the last known queue length to detect when chunks finish
     let mut last_sink_len: usize = 0;
 
     loop {
@@ -186,29 +419,24 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
         if let Some(cmd) = cmd {
             match cmd {
                 PlaybackCmd::StartSession { session_id } => {
-                    // Stop any existing playback
-                    if let Some(sink) = session_sink.take() {
-                        sink.stop();
-                    }
-
-                    // Clear pending chunks
+                    // Clear pending chunks and (re)initialize the backend output
                     pending_by_index.clear();
 
-                    // Create new persistent sink for this session
-                    match Sink::try_new(&stream_handle) {
-                        Ok(sink) => {
-                            session_sink = Some(sink);
-                        }
+                    match backend.reset() {
+                        Ok(()) => session_active = true,
                         Err(e) => {
+                            session_active = false;
+                            let message = format!("Failed to create audio sink: {}", e);
                             emit_event(
                                 &app,
                                 TtsPlaybackEvent {
                                     session_id: session_id.clone(),
                                     chunk_index: 0,
                                     event: "error".to_string(),
-                                    message: Some(format!("Failed to create audio sink: {}", e)),
+                                    message: Some(message.clone()),
                                 },
                             );
+                            emit_status(&app, &session_id, PlaybackLifecycleStatus::Error { message });
                         }
                     }
 
@@ -218,11 +446,18 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                     chunks_queued_to_sink = 0;
                     current_playing_chunk = 0;
                     last_sink_len = 0;
+                    appended.clear();
+                    chunk_durations.clear();
+                    completed_duration = Duration::ZERO;
+                    chunk_started_at = None;
+                    active_timeline = None;
+                    last_word_index = None;
 
                     status.is_playing.store(false, Ordering::SeqCst);
                     status.is_paused.store(false, Ordering::SeqCst);
                     status.queued_count.store(0, Ordering::SeqCst);
                     status.current_chunk.store(0, Ordering::SeqCst);
+                    status.position_ms.store(0, Ordering::SeqCst);
                 }
 
                 PlaybackCmd::EnqueueWav(chunk) => {
@@ -232,7 +467,10 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                     }
 
                     // Store chunk (may be out of order)
-                    pending_by_index.insert(chunk.chunk_index, (chunk.wav_data, chunk.speed));
+                    pending_by_index.insert(
+                        chunk.chunk_index,
+                        PendingChunk::Wav(chunk.wav_data, chunk.speed),
+                    );
 
                     emit_event(
                         &app,
@@ -245,35 +483,180 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                     );
                 }
 
-                PlaybackCmd::Stop => {
-                    if let Some(sink) = session_sink.take() {
-                        sink.stop();
+                PlaybackCmd::EnqueueStream(chunk) => {
+                    // Ignore chunks from old sessions
+                    if active_session.as_deref() != Some(chunk.session_id.as_str()) {
+                        continue;
                     }
+
+                    // Store chunk (may be out of order)
+                    pending_by_index.insert(
+                        chunk.chunk_index,
+                        PendingChunk::Stream(chunk.source, chunk.speed, chunk.timeline),
+                    );
+
+                    emit_event(
+                        &app,
+                        TtsPlaybackEvent {
+                            session_id: chunk.session_id,
+                            chunk_index: chunk.chunk_index,
+                            event: "chunk_ready".to_string(),
+                            message: None,
+                        },
+                    );
+                }
+
+                PlaybackCmd::Stop => {
+                    backend.stop();
+                    session_active = false;
                     pending_by_index.clear();
+                    finish_recording(
+                        &mut recording,
+                        &app,
+                        &active_session,
+                        current_playing_chunk,
+                    );
+                    if let Some(session_id) = active_session.as_deref() {
+                        emit_status(&app, session_id, PlaybackLifecycleStatus::Finished);
+                    }
                     active_session = None;
                     next_expected_index = 0;
                     chunks_queued_to_sink = 0;
                     current_playing_chunk = 0;
                     last_sink_len = 0;
+                    appended.clear();
+                    chunk_durations.clear();
+                    completed_duration = Duration::ZERO;
+                    chunk_started_at = None;
+                    active_timeline = None;
+                    last_word_index = None;
 
                     status.is_playing.store(false, Ordering::SeqCst);
                     status.is_paused.store(false, Ordering::SeqCst);
                     status.queued_count.store(0, Ordering::SeqCst);
+                    status.position_ms.store(0, Ordering::SeqCst);
                 }
 
                 PlaybackCmd::Pause => {
-                    if let Some(sink) = session_sink.as_ref() {
-                        sink.pause();
-                        status.is_paused.store(true, Ordering::SeqCst);
+                    backend.pause();
+                    status.is_paused.store(true, Ordering::SeqCst);
+                    if let Some(session_id) = active_session.as_deref() {
+                        emit_status(&app, session_id, PlaybackLifecycleStatus::Paused);
                     }
                 }
 
                 PlaybackCmd::Resume => {
-                    if let Some(sink) = session_sink.as_ref() {
-                        sink.play();
-                        status.is_paused.store(false, Ordering::SeqCst);
+                    backend.resume();
+                    status.is_paused.store(false, Ordering::SeqCst);
+                    if let Some(session_id) = active_session.as_deref() {
+                        emit_status(&app, session_id, PlaybackLifecycleStatus::Resumed);
                     }
                 }
+
+                PlaybackCmd::Seek(target) => {
+                    if !session_active || chunk_durations.is_empty() {
+                        continue;
+                    }
+                    seek_to(
+                        target,
+                        &app,
+                        backend.as_mut(),
+                        &status,
+                        &active_session,
+                        &appended,
+                        &chunk_durations,
+                        &mut completed_duration,
+                        &mut chunk_started_at,
+                        &mut current_playing_chunk,
+                    );
+                }
+
+                PlaybackCmd::Next => {
+                    if !session_active || chunk_durations.is_empty() {
+                        continue;
+                    }
+                    let target = chunk_boundary(&chunk_durations, current_playing_chunk + 1);
+                    seek_to(
+                        target,
+                        &app,
+                        backend.as_mut(),
+                        &status,
+                        &active_session,
+                        &appended,
+                        &chunk_durations,
+                        &mut completed_duration,
+                        &mut chunk_started_at,
+                        &mut current_playing_chunk,
+                    );
+                }
+
+                PlaybackCmd::Previous => {
+                    if !session_active || chunk_durations.is_empty() {
+                        continue;
+                    }
+                    let target =
+                        chunk_boundary(&chunk_durations, current_playing_chunk.saturating_sub(1));
+                    seek_to(
+                        target,
+                        &app,
+                        backend.as_mut(),
+                        &status,
+                        &active_session,
+                        &appended,
+                        &chunk_durations,
+                        &mut completed_duration,
+                        &mut chunk_started_at,
+                        &mut current_playing_chunk,
+                    );
+                }
+
+                PlaybackCmd::SetBackend { name, device } => {
+                    // Tear down the current output and swap in the chosen backend.
+                    backend.stop();
+                    session_active = false;
+                    match (audio_backend::builder_for(&name))(device) {
+                        Ok(b) => {
+                            backend = b;
+                            emit_event(
+                                &app,
+                                TtsPlaybackEvent {
+                                    session_id: active_session.clone().unwrap_or_default(),
+                                    chunk_index: 0,
+                                    event: "backend_changed".to_string(),
+                                    message: Some(name),
+                                },
+                            );
+                        }
+                        Err(e) => {
+                            emit_event(
+                                &app,
+                                TtsPlaybackEvent {
+                                    session_id: active_session.clone().unwrap_or_default(),
+                                    chunk_index: 0,
+                                    event: "error".to_string(),
+                                    message: Some(format!("Failed to open backend '{}': {}", name, e)),
+                                },
+                            );
+                        }
+                    }
+                }
+
+                PlaybackCmd::StartRecording { path, format } => {
+                    recording = Some(RecordingWriter::new(path, format));
+                }
+
+                PlaybackCmd::StopRecording => {
+                    finish_recording(
+                        &mut recording,
+                        &app,
+                        &active_session,
+                        current_playing_chunk,
+                    );
+                }
+
+                PlaybackCmd::SetSpatial(config) => {
+                    backend.set_spatial(config);
+                }
             }
         }
 
@@ -282,24 +665,77 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
             continue;
         };
 
-        let Some(sink) = session_sink.as_ref() else {
+        if !session_active {
             continue;
-        };
+        }
 
         // Append any pending chunks that are ready (in order)
-        while let Some((wav_data, speed)) = pending_by_index.remove(&next_expected_index) {
-            let cursor = Cursor::new(wav_data);
-            match Decoder::new(cursor) {
-                Ok(source) => {
-                    // Apply speed adjustment and append to sink
-                    let source = source.speed(speed.clamp(0.5, 2.0));
-                    sink.append(source);
-
+        while let Some(pending) = pending_by_index.remove(&next_expected_index) {
+            // Duration this chunk contributes at its playback speed (kept for
+            // seeking/position reporting) and the append itself. Streamed
+            // chunks have no duration known up front and can't be replayed
+            // from memory, so they're recorded with a zero duration and an
+            // empty placeholder in `appended` -- seeking across one lands on
+            // the nearest WAV-backed chunk instead of replaying it.
+            // Alongside the append itself, decode WAV chunks to PCM for the
+            // active recording (if any). Streamed chunks are consumed
+            // directly by the backend and can't be "teed", so -- like
+            // `seek_to` across them -- a recording simply has a gap where
+            // one played; this is the same documented tradeoff.
+            let (append_result, chunk_duration, seek_entry, recorded, stream_timeline) =
+                match pending {
+                    PendingChunk::Wav(wav_data, speed) => {
+                        let duration = wav_duration(&wav_data)
+                            .map(|d| d.div_f32(speed.clamp(0.5, 2.0)))
+                            .unwrap_or(Duration::ZERO);
+                        let wav_copy = wav_data.clone();
+                        let recorded = if recording.is_some() {
+                            decode_wav_samples(&wav_copy)
+                                .map(|(pcm, rate)| (pcm, (rate as f32 * speed) as u32))
+                        } else {
+                            None
+                        };
+                        (
+                            backend.append_wav(wav_data, speed),
+                            duration,
+                            (wav_copy, speed),
+                            recorded,
+                            None,
+                        )
+                    }
+                    PendingChunk::Stream(source, speed, timeline) => {
+                        let stream_timeline =
+                            timeline.map(|t| (t, source.samples_played_handle()));
+                        (
+                            backend.append_source(source, speed),
+                            Duration::ZERO,
+                            (Vec::new(), speed),
+                            None,
+                            stream_timeline,
+                        )
+                    }
+                };
+            match append_result {
+                Ok(()) => {
+                    if let (Some(writer), Some((pcm, rate))) =
+                        (recording.as_mut(), recorded.as_ref())
+                    {
+                        writer.feed(pcm, *rate);
+                    }
+                    if stream_timeline.is_some() {
+                        active_timeline = stream_timeline;
+                        last_word_index = None;
+                    }
+                    appended.push(seek_entry);
+                    chunk_durations.push(chunk_duration);
                     chunks_queued_to_sink += 1;
                     next_expected_index += 1;
+                    if chunk_started_at.is_none() {
+                        chunk_started_at = Some(Instant::now());
+                    }
 
                     // Update status
-                    let queued = sink.len();
+                    let queued = backend.queued_len();
                     status.queued_count.store(queued, Ordering::SeqCst);
 
                     // If this is the first chunk OR we ran dry (last_sink_len == 0), emit started event
@@ -314,6 +750,17 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                                 message: None,
                             },
                         );
+                        emit_status(
+                            &app,
+                            &session_id,
+                            if current_playing_chunk == 0 {
+                                PlaybackLifecycleStatus::Started
+                            } else {
+                                PlaybackLifecycleStatus::ChunkPlaying {
+                                    index: current_playing_chunk,
+                                }
+                            },
+                        );
                         // Do NOT reset current_playing_chunk here, we are continuing
                         last_sink_len = queued;
                     }
@@ -329,25 +776,49 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                     );
                 }
                 Err(e) => {
+                    let message = format!("Failed to queue chunk audio: {}", e);
                     emit_event(
                         &app,
                         TtsPlaybackEvent {
                             session_id: session_id.clone(),
                             chunk_index: next_expected_index,
                             event: "error".to_string(),
-                            message: Some(format!("Failed to decode WAV: {}", e)),
+                            message: Some(message.clone()),
                         },
                     );
+                    emit_status(&app, &session_id, PlaybackLifecycleStatus::Error { message });
                     next_expected_index += 1;
                 }
             }
         }
 
-        // Monitor sink state for chunk transitions
-        let current_len = sink.len();
+        // Monitor backend state for chunk transitions
+        let current_len = backend.queued_len();
+
+        // Push a `word_highlight` event whenever the live sample counter for
+        // the currently-streaming chunk crosses into a new word's span. The
+        // counter only advances while the backend is actually pulling
+        // samples, so this naturally freezes when paused.
+        if let Some((timeline, samples_played)) = active_timeline.as_ref() {
+            let samples_played = samples_played.load(Ordering::SeqCst);
+            if let Some(word_index) = timeline.current_word_index(samples_played) {
+                if last_word_index != Some(word_index) {
+                    last_word_index = Some(word_index);
+                    emit_event(
+                        &app,
+                        TtsPlaybackEvent {
+                            session_id: session_id.clone(),
+                            chunk_index: current_playing_chunk,
+                            event: "word_highlight".to_string(),
+                            message: Some(word_index.to_string()),
+                        },
+                    );
+                }
+            }
+        }
 
         // Update pause state
-        if sink.is_paused() {
+        if backend.is_paused() {
             status.is_paused.store(true, Ordering::SeqCst);
         } else {
             status.is_paused.store(false, Ordering::SeqCst);
@@ -366,6 +837,12 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                 },
             );
 
+            // Bank the finished chunk's duration and restart the intra-chunk clock.
+            if let Some(d) = chunk_durations.get(current_playing_chunk) {
+                completed_duration += *d;
+            }
+            chunk_started_at = Some(Instant::now());
+
             current_playing_chunk += 1;
             status
                 .current_chunk
@@ -381,10 +858,17 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                     message: None,
                 },
             );
+            emit_status(
+                &app,
+                &session_id,
+                PlaybackLifecycleStatus::ChunkPlaying {
+                    index: current_playing_chunk,
+                },
+            );
         }
 
-        // Detect when all playback is done (sink became empty)
-        if sink.empty() && chunks_queued_to_sink > 0 {
+        // Detect when all playback is done (backend queue became empty)
+        if backend.is_empty() && chunks_queued_to_sink > 0 {
             // Last chunk in the sink finished
             emit_event(
                 &app,
@@ -396,6 +880,15 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
                 },
             );
 
+            // Bank the final chunk's duration and stop the intra-chunk clock.
+            if let Some(d) = chunk_durations.get(current_playing_chunk) {
+                completed_duration += *d;
+            }
+            chunk_started_at = None;
+            status
+                .position_ms
+                .store(completed_duration.as_millis() as u64, Ordering::SeqCst);
+
             // Increment current chunk index because we just finished one
             current_playing_chunk += 1;
             status
@@ -416,5 +909,241 @@ fn audio_thread_main(app: AppHandle, rx: mpsc::Receiver<PlaybackCmd>, status: Ar
 
         last_sink_len = current_len;
         status.queued_count.store(current_len, Ordering::SeqCst);
+
+        // Report the global position roughly every 250ms while audio is moving.
+        if !backend.is_paused() {
+            if let Some(started) = chunk_started_at {
+                let position = completed_duration + started.elapsed();
+                status
+                    .position_ms
+                    .store(position.as_millis() as u64, Ordering::SeqCst);
+                if last_position_emit.elapsed() >= Duration::from_millis(250) {
+                    last_position_emit = Instant::now();
+                    emit_event(
+                        &app,
+                        TtsPlaybackEvent {
+                            session_id: session_id.clone(),
+                            chunk_index: current_playing_chunk,
+                            event: "position".to_string(),
+                            message: Some(format!("{}ms", position.as_millis())),
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Sum of chunk durations up to (not including) `idx`, clamped to the start
+/// of the last available chunk. Used by `Next`/`Previous` to find the time
+/// offset of a chunk boundary without re-deriving prefix sums themselves.
+fn chunk_boundary(chunk_durations: &[Duration], idx: usize) -> Duration {
+    let idx = idx.min(chunk_durations.len().saturating_sub(1));
+    chunk_durations[..idx].iter().sum()
+}
+
+/// Binary-search `chunk_durations`' prefix sum for the chunk containing
+/// `target`, clamping to the last available chunk if `target` falls past
+/// everything generated so far. Returns `(chunk_idx, intra_chunk_offset,
+/// prefix_sums)`, where `prefix_sums[i]` is the cumulative duration before
+/// chunk `i` (so `prefix_sums.len() == chunk_durations.len() + 1`).
+fn locate_seek_target(
+    chunk_durations: &[Duration],
+    target: Duration,
+) -> (usize, Duration, Vec<Duration>) {
+    let mut prefix = Vec::with_capacity(chunk_durations.len() + 1);
+    let mut acc = Duration::ZERO;
+    prefix.push(acc);
+    for d in chunk_durations {
+        acc += *d;
+        prefix.push(acc);
+    }
+    let clamped = target.min(acc);
+    let chunk_idx = match prefix[1..].partition_point(|&p| p <= clamped) {
+        i if i >= chunk_durations.len() => chunk_durations.len().saturating_sub(1),
+        i => i,
+    };
+    let intra = clamped.saturating_sub(prefix[chunk_idx]);
+    (chunk_idx, intra, prefix)
+}
+
+#[cfg(test)]
+mod seek_tests {
+    use super::*;
+
+    fn secs(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|s| Duration::from_secs(*s)).collect()
+    }
+
+    #[test]
+    fn locates_target_within_first_chunk() {
+        let durations = secs(&[5, 5, 5]);
+        let (idx, intra, _) = locate_seek_target(&durations, Duration::from_secs(2));
+        assert_eq!(idx, 0);
+        assert_eq!(intra, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn locates_target_on_a_chunk_boundary() {
+        let durations = secs(&[5, 5, 5]);
+        let (idx, intra, _) = locate_seek_target(&durations, Duration::from_secs(5));
+        assert_eq!(idx, 1);
+        assert_eq!(intra, Duration::ZERO);
+    }
+
+    #[test]
+    fn locates_target_within_a_later_chunk() {
+        let durations = secs(&[5, 5, 5]);
+        let (idx, intra, _) = locate_seek_target(&durations, Duration::from_secs(11));
+        assert_eq!(idx, 2);
+        assert_eq!(intra, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn clamps_past_the_last_generated_chunk() {
+        let durations = secs(&[5, 5, 5]);
+        let (idx, intra, _) = locate_seek_target(&durations, Duration::from_secs(100));
+        assert_eq!(idx, 2);
+        assert_eq!(intra, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn handles_no_chunks_generated_yet() {
+        let durations: Vec<Duration> = Vec::new();
+        let (idx, intra, _) = locate_seek_target(&durations, Duration::from_secs(3));
+        assert_eq!(idx, 0);
+        assert_eq!(intra, Duration::ZERO);
+    }
+}
+
+/// Shared rebuild-and-reposition logic for `Seek`/`Next`/`Previous`: finds the
+/// chunk containing `target`, re-appends the backend's queue starting there
+/// (trimming the leading chunk to the intra-chunk offset), and updates the
+/// position/status bookkeeping in place.
+#[allow(clippy::too_many_arguments)]
+fn seek_to(
+    target: Duration,
+    app: &AppHandle,
+    backend: &mut dyn AudioBackend,
+    status: &PlaybackStatus,
+    active_session: &Option<String>,
+    appended: &[(Vec<u8>, f32)],
+    chunk_durations: &[Duration],
+    completed_duration: &mut Duration,
+    chunk_started_at: &mut Option<Instant>,
+    current_playing_chunk: &mut usize,
+) {
+    let was_paused = status.is_paused.load(Ordering::SeqCst);
+
+    let (chunk_idx, intra, prefix) = locate_seek_target(chunk_durations, target);
+
+    // Rebuild output starting at the target chunk: trim the first chunk to
+    // the intra-chunk offset, then re-append the rest. Streamed chunks leave
+    // an empty placeholder here (their audio was never buffered in memory)
+    // and are simply skipped -- seeking across one means losing its audio.
+    if backend.reset().is_ok() {
+        for (i, (wav, speed)) in appended.iter().enumerate().skip(chunk_idx) {
+            if wav.is_empty() {
+                continue;
+            }
+            let data = if i == chunk_idx && !intra.is_zero() {
+                trim_wav(wav, intra)
+            } else {
+                wav.clone()
+            };
+            let _ = backend.append_wav(data, *speed);
+        }
+        if was_paused {
+            backend.pause();
+        }
+    }
+
+    *completed_duration = prefix[chunk_idx] + intra;
+    *chunk_started_at = Some(Instant::now());
+    *current_playing_chunk = chunk_idx;
+    status.current_chunk.store(chunk_idx, Ordering::SeqCst);
+    status
+        .position_ms
+        .store(completed_duration.as_millis() as u64, Ordering::SeqCst);
+
+    emit_event(
+        app,
+        TtsPlaybackEvent {
+            session_id: active_session.clone().unwrap_or_default(),
+            chunk_index: chunk_idx,
+            event: "seeked".to_string(),
+            message: Some(format!("{}ms", completed_duration.as_millis())),
+        },
+    );
+}
+
+/// Decode a WAV buffer and return its playback duration, if it parses.
+fn wav_duration(wav: &[u8]) -> Option<Duration> {
+    use rodio::Source;
+    let decoder = rodio::Decoder::new(std::io::Cursor::new(wav.to_vec())).ok()?;
+    if let Some(d) = decoder.total_duration() {
+        return Some(d);
+    }
+    // Fall back to counting samples when the decoder can't report duration.
+    let channels = decoder.channels().max(1) as u64;
+    let sample_rate = decoder.sample_rate().max(1) as u64;
+    let frames = decoder.count() as u64 / channels;
+    Some(Duration::from_secs_f64(frames as f64 / sample_rate as f64))
+}
+
+/// Decode a WAV buffer to mono f32 PCM and its native sample rate, for
+/// feeding into a `RecordingWriter`. Multi-channel audio is downmixed by
+/// averaging channels.
+fn decode_wav_samples(wav: &[u8]) -> Option<(Vec<f32>, u32)> {
+    use rodio::Source;
+    let decoder = rodio::Decoder::new(std::io::Cursor::new(wav.to_vec())).ok()?;
+    let channels = decoder.channels().max(1) as usize;
+    let sample_rate = decoder.sample_rate();
+    let samples: Vec<f32> = decoder.convert_samples().collect();
+    if channels <= 1 {
+        return Some((samples, sample_rate));
+    }
+    let mono = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect();
+    Some((mono, sample_rate))
+}
+
+/// Re-encode `wav` as a WAV buffer with the leading `offset` of audio removed,
+/// so playback can resume mid-chunk after a seek.
+fn trim_wav(wav: &[u8], offset: Duration) -> Vec<u8> {
+    use rodio::Source;
+    let decoder = match rodio::Decoder::new(std::io::Cursor::new(wav.to_vec())) {
+        Ok(d) => d,
+        Err(_) => return wav.to_vec(),
+    };
+    let channels = decoder.channels().max(1);
+    let sample_rate = decoder.sample_rate().max(1);
+    let skip = (offset.as_secs_f64() * sample_rate as f64) as usize * channels as usize;
+    let samples: Vec<i16> = decoder.convert_samples::<i16>().skip(skip).collect();
+
+    // Re-wrap the trimmed PCM in a WAV container (same layout as
+    // `TTSResult::to_wav`, generalized to the decoded channel count).
+    let data_size = samples.len() * 2;
+    let byte_rate = sample_rate * channels as u32 * 2;
+    let block_align = channels * 2;
+    let mut buffer = Vec::with_capacity(44 + data_size);
+    buffer.extend_from_slice(b"RIFF");
+    buffer.extend_from_slice(&((36 + data_size) as u32).to_le_bytes());
+    buffer.extend_from_slice(b"WAVE");
+    buffer.extend_from_slice(b"fmt ");
+    buffer.extend_from_slice(&16u32.to_le_bytes());
+    buffer.extend_from_slice(&1u16.to_le_bytes());
+    buffer.extend_from_slice(&channels.to_le_bytes());
+    buffer.extend_from_slice(&sample_rate.to_le_bytes());
+    buffer.extend_from_slice(&byte_rate.to_le_bytes());
+    buffer.extend_from_slice(&block_align.to_le_bytes());
+    buffer.extend_from_slice(&16u16.to_le_bytes());
+    buffer.extend_from_slice(b"data");
+    buffer.extend_from_slice(&(data_size as u32).to_le_bytes());
+    for s in samples {
+        buffer.extend_from_slice(&s.to_le_bytes());
     }
+    buffer
 }
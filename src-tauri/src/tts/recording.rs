@@ -0,0 +1,90 @@
+//! Continuous whole-session recording to a single audio file.
+//!
+//! Mirrors `SegmentWriter`'s "accumulate PCM, encode on flush" approach, but
+//! keeps one file for the whole session instead of rolling over at chapter
+//! or size boundaries. Fed from the same in-order `next_expected_index` path
+//! that appends chunks to the sink, so WAV chunks land in the file as they
+//! were played. Streamed chunks are not captured and leave a gap.
+
+use crate::tts::kokoro::{AudioFormat, AudioMetadata, TTSResult};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RecordingError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Encoding error: {0}")]
+    EncodeError(String),
+}
+
+/// Accumulates decoded mono PCM for the life of a recording and encodes it to
+/// `path` in `format` once stopped.
+///
+/// Chunks can arrive at different native sample rates (different engines, or
+/// the same engine at different playback speeds). `feed` resamples each one
+/// to the rate established by the first chunk, so the final file is one
+/// consistent stream rather than a series of mismatched segments.
+pub struct RecordingWriter {
+    path: PathBuf,
+    format: AudioFormat,
+    sample_rate: Option<u32>,
+    samples: Vec<f32>,
+}
+
+impl RecordingWriter {
+    pub fn new(path: PathBuf, format: AudioFormat) -> Self {
+        Self {
+            path,
+            format,
+            sample_rate: None,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Append mono PCM sampled at `rate`, resampling to the rate established
+    /// by the first call if this chunk's rate differs.
+    pub fn feed(&mut self, pcm: &[f32], rate: u32) {
+        let target_rate = *self.sample_rate.get_or_insert(rate);
+        if rate == target_rate {
+            self.samples.extend_from_slice(pcm);
+        } else {
+            self.samples.extend(resample_linear(pcm, rate, target_rate));
+        }
+    }
+
+    /// Encode everything recorded so far and write it to `path`, returning
+    /// the path on success.
+    pub fn finish(self) -> Result<PathBuf, RecordingError> {
+        let result = TTSResult {
+            audio: self.samples,
+            sample_rate: self.sample_rate.unwrap_or(24000),
+        };
+        let bytes = result
+            .encode(self.format, &AudioMetadata::default())
+            .map_err(|e| RecordingError::EncodeError(e.to_string()))?;
+        std::fs::write(&self.path, &bytes)?;
+        Ok(self.path)
+    }
+}
+
+/// Naive linear-interpolation resample. Good enough for aligning spoken-word
+/// chunks recorded at slightly different rates; not worth a dedicated
+/// resampling dependency for this.
+fn resample_linear(pcm: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if pcm.is_empty() || from_rate == to_rate || from_rate == 0 {
+        return pcm.to_vec();
+    }
+    let ratio = to_rate as f64 / from_rate as f64;
+    let out_len = ((pcm.len() as f64) * ratio).round() as usize;
+    (0..out_len)
+        .map(|i| {
+            let src_pos = i as f64 / ratio;
+            let idx = src_pos.floor() as usize;
+            let frac = src_pos - idx as f64;
+            let a = pcm.get(idx).copied().unwrap_or(0.0);
+            let b = pcm.get(idx + 1).copied().unwrap_or(a);
+            a + (b - a) * frac as f32
+        })
+        .collect()
+}
@@ -0,0 +1,152 @@
+//! On-the-fly output-rate resampling stage.
+//!
+//! TTS engines here emit fixed rates (e.g. Echo/Mimi's 24000 Hz) while
+//! output devices commonly run at 44100 or 48000 Hz. Left
+//! alone, rodio falls back to its own internal resampler, which is cheap but
+//! audibly soft. `ResampledSource` instead wraps a source with `rubato`'s
+//! sinc interpolator, processing in fixed-size blocks the same way
+//! [`crate::tts::spatial::BinauralSource`] processes HRIR convolution
+//! blocks: pull a fixed-size chunk from the inner source, run it through the
+//! resampler, buffer the output, and drain that buffer sample-by-sample from
+//! `next()`, refilling whenever it runs dry.
+
+use rodio::Source;
+use rubato::{
+    Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Fixed input frame size fed to the resampler each refill. `SincFixedIn`
+/// expects the same input length on every call.
+const CHUNK_SIZE: usize = 1024;
+
+/// A rodio `Source` that resamples its (mono) inner source from its native
+/// rate to `target_rate`, so the sink plays back at the device's actual rate
+/// directly instead of leaning on rodio's own internal resampler.
+pub struct ResampledSource<S> {
+    inner: S,
+    resampler: SincFixedIn<f32>,
+    target_rate: u32,
+    /// Resampled samples ready to hand out via `next()`.
+    output: VecDeque<f32>,
+    inner_exhausted: bool,
+    /// The resampler's internal delay line has been flushed with a final
+    /// zero-padded block; once its output drains, playback truly ends.
+    flushed: bool,
+}
+
+impl<S: Source<Item = f32>> ResampledSource<S> {
+    /// Wrap `inner` (a mono source) so it plays back resampled to
+    /// `target_rate`. Always does real work, even if the rates already
+    /// match -- callers that want to skip resampling entirely should check
+    /// `inner.sample_rate() == target_rate` themselves before wrapping.
+    pub fn new(inner: S, target_rate: u32) -> Self {
+        let params = SincInterpolationParameters {
+            sinc_len: 256,
+            f_cutoff: 0.95,
+            interpolation: SincInterpolationType::Linear,
+            oversampling_factor: 256,
+            window: WindowFunction::BlackmanHarris2,
+        };
+        let resampler = SincFixedIn::<f32>::new(
+            target_rate as f64 / inner.sample_rate() as f64,
+            2.0,
+            params,
+            CHUNK_SIZE,
+            1,
+        )
+        .expect("resample ratio and chunk size are fixed and always valid");
+
+        Self {
+            inner,
+            resampler,
+            target_rate,
+            output: VecDeque::new(),
+            inner_exhausted: false,
+            flushed: false,
+        }
+    }
+
+    /// Pull the next `CHUNK_SIZE`-sample frame from the inner source. Returns
+    /// `None` once the inner source has nothing left to give.
+    fn next_input_chunk(&mut self) -> Option<Vec<f32>> {
+        if self.inner_exhausted {
+            return None;
+        }
+        let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+        for _ in 0..CHUNK_SIZE {
+            match self.inner.next() {
+                Some(sample) => chunk.push(sample),
+                None => {
+                    self.inner_exhausted = true;
+                    break;
+                }
+            }
+        }
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    /// Run the resampler on the next available input, refilling `output`.
+    /// Returns `false` once there's nothing left, including the flushed
+    /// delay-line tail.
+    fn refill(&mut self) -> bool {
+        if self.flushed {
+            return false;
+        }
+
+        let Some(mut chunk) = self.next_input_chunk() else {
+            // The inner source is exhausted: flush the resampler's internal
+            // delay line with one final zero-padded block so its tail
+            // samples aren't dropped.
+            self.flushed = true;
+            let input = vec![vec![0.0f32; CHUNK_SIZE]];
+            if let Ok(result) = self.resampler.process(&input, None) {
+                self.output.extend(result[0].iter().copied());
+            }
+            return !self.output.is_empty();
+        };
+
+        chunk.resize(CHUNK_SIZE, 0.0);
+        match self.resampler.process(&[chunk], None) {
+            Ok(result) => {
+                self.output.extend(result[0].iter().copied());
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl<S: Source<Item = f32>> Iterator for ResampledSource<S> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.output.is_empty() && !self.refill() {
+            return None;
+        }
+        self.output.pop_front()
+    }
+}
+
+impl<S: Source<Item = f32>> Source for ResampledSource<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.target_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
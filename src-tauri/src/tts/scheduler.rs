@@ -0,0 +1,232 @@
+//! Sentence-level TTS prefetch scheduler with an on-disk PCM cache.
+//!
+//! `generate_streaming` is a one-shot call that takes the single
+//! `EchoManager` engine for the whole stream, so any other call errors until
+//! it's handed back, and without this there's always a stall between
+//! sentences while the next one synthesizes.
+//! `TtsScheduler` splits a chapter into sentence-sized units and, borrowing
+//! the fetch-ahead/fetch-blocking split from librespot's
+//! `StreamLoaderController`, keeps synthesis running ahead of playback:
+//! `current()` generates (or waits for) the unit at the play head right now
+//! -- "fetch blocking" -- while a background task prefetches up to
+//! `PREFETCH_DEPTH` units past it -- "fetch ahead". Completed units are
+//! persisted to a content-addressed cache on disk keyed by `(text_hash,
+//! speaker_id, temperature, sample_rate)`, so re-reading a chapter or
+//! seeking back to an already-synthesized sentence is instant.
+
+use crate::epub::Chapter;
+use crate::tts::echo_tts::{EchoError, EchoManager};
+use crate::tts::kokoro::TTSResult;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// How many units beyond the current one the background task keeps
+/// synthesized ahead of playback.
+const PREFETCH_DEPTH: usize = 2;
+
+/// Voice/generation parameters that key the on-disk cache alongside a
+/// unit's text -- a cached WAV is only reusable if all of these match.
+#[derive(Debug, Clone, Copy)]
+pub struct SynthesisParams {
+    pub speaker_id: u32,
+    pub temperature: f64,
+    pub sample_rate: u32,
+}
+
+struct SchedulerState {
+    units: Vec<String>,
+    /// Units a prefetch run (or a blocking `current()` call) has already
+    /// synthesized, not yet claimed by playback.
+    ready: HashMap<usize, TTSResult>,
+}
+
+/// Splits a chapter into sentence-sized units and keeps synthesis of the
+/// next few units running ahead of playback. See the module docs for the
+/// fetch-ahead/fetch-blocking split this follows.
+pub struct TtsScheduler {
+    echo: Arc<EchoManager>,
+    cache_dir: PathBuf,
+    params: SynthesisParams,
+    state: Arc<Mutex<SchedulerState>>,
+    /// Bumped on every `load_chapter`/`seek_to`. A background prefetch run
+    /// captures the current value and checks it before publishing each
+    /// unit, so a seek or chapter change discards stale look-ahead work for
+    /// free instead of needing to track and abort task handles.
+    generation: Arc<AtomicU64>,
+}
+
+impl TtsScheduler {
+    /// `cache_dir` is created on demand; it should be a subdirectory
+    /// dedicated to this cache (e.g. under the app's data dir), since
+    /// entries are never evicted.
+    pub fn new(echo: Arc<EchoManager>, cache_dir: PathBuf, params: SynthesisParams) -> Self {
+        Self {
+            echo,
+            cache_dir,
+            params,
+            state: Arc::new(Mutex::new(SchedulerState {
+                units: Vec::new(),
+                ready: HashMap::new(),
+            })),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Split `chapter` into sentence-sized units, discard any in-flight
+    /// look-ahead work from whatever was loaded before, and start
+    /// prefetching from the beginning.
+    pub async fn load_chapter(&self, chapter: &Chapter) {
+        let units = split_sentences(&chapter.content);
+        {
+            let mut state = self.state.lock().await;
+            state.units = units;
+            state.ready.clear();
+        }
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.spawn_prefetch(0);
+    }
+
+    /// Jump the play head to `unit_index`: discard any in-flight look-ahead
+    /// run and restart prefetch from there. Units already in `ready` are kept.
+    pub fn seek_to(&self, unit_index: usize) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.spawn_prefetch(unit_index);
+    }
+
+    /// Number of sentence-sized units in the loaded chapter.
+    pub async fn unit_count(&self) -> usize {
+        self.state.lock().await.units.len()
+    }
+
+    /// The unit at `unit_index`: returned instantly if prefetch (or a
+    /// previous call) already produced it, otherwise synthesized inline
+    /// right now -- the blocking fallback for when playback catches up to
+    /// an un-synthesized unit. Also kicks off prefetch for what comes next.
+    pub async fn current(&self, unit_index: usize) -> Result<TTSResult, EchoError> {
+        if let Some(result) = self.state.lock().await.ready.remove(&unit_index) {
+            self.spawn_prefetch(unit_index + 1);
+            return Ok(result);
+        }
+        let text = self
+            .state
+            .lock()
+            .await
+            .units
+            .get(unit_index)
+            .cloned()
+            .ok_or_else(|| EchoError::GenerationError("unit index out of range".to_string()))?;
+
+        let result = synthesize(&self.echo, &self.cache_dir, &text, self.params).await?;
+        self.spawn_prefetch(unit_index + 1);
+        Ok(result)
+    }
+
+    /// Spawn a background task that synthesizes units `from..from +
+    /// PREFETCH_DEPTH` in order, bailing out early if `generation` advances
+    /// (a seek or chapter change superseded this run) or the chapter ends.
+    fn spawn_prefetch(&self, from: usize) {
+        let echo = Arc::clone(&self.echo);
+        let cache_dir = self.cache_dir.clone();
+        let params = self.params;
+        let state = Arc::clone(&self.state);
+        let generation = Arc::clone(&self.generation);
+        let my_generation = generation.load(Ordering::SeqCst);
+
+        tokio::spawn(async move {
+            for unit_index in from..from + PREFETCH_DEPTH {
+                if generation.load(Ordering::SeqCst) != my_generation {
+                    return;
+                }
+                let text = {
+                    let state = state.lock().await;
+                    if state.ready.contains_key(&unit_index) {
+                        continue;
+                    }
+                    match state.units.get(unit_index) {
+                        Some(text) => text.clone(),
+                        None => return, // past the end of the chapter
+                    }
+                };
+
+                let result = synthesize(&echo, &cache_dir, &text, params).await;
+
+                if generation.load(Ordering::SeqCst) != my_generation {
+                    return; // superseded while we were synthesizing
+                }
+                if let Ok(result) = result {
+                    state.lock().await.ready.insert(unit_index, result);
+                }
+            }
+        });
+    }
+}
+
+/// Check the on-disk cache for `text` under `params`, falling back to
+/// synthesizing it with `echo` and persisting the result.
+async fn synthesize(
+    echo: &Arc<EchoManager>,
+    cache_dir: &Path,
+    text: &str,
+    params: SynthesisParams,
+) -> Result<TTSResult, EchoError> {
+    let cache_path = cache_path(cache_dir, text, params);
+    if let Some(cached) = load_from_cache(&cache_path, params.sample_rate) {
+        return Ok(cached);
+    }
+
+    let result = echo
+        .generate(text, params.speaker_id, params.temperature)
+        .await?;
+    store_to_cache(&cache_path, &result);
+    Ok(result)
+}
+
+/// Content-addressed cache file path for `text` under `params`.
+fn cache_path(cache_dir: &Path, text: &str, params: SynthesisParams) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    let text_hash = hasher.finish();
+
+    cache_dir.join(format!(
+        "{:016x}-{}-{:x}-{}.wav",
+        text_hash,
+        params.speaker_id,
+        params.temperature.to_bits(),
+        params.sample_rate
+    ))
+}
+
+/// Load a cached WAV and decode it back to PCM, if present.
+fn load_from_cache(path: &Path, sample_rate: u32) -> Option<TTSResult> {
+    use rodio::Source;
+    let bytes = std::fs::read(path).ok()?;
+    let decoder = rodio::Decoder::new(std::io::Cursor::new(bytes)).ok()?;
+    let audio: Vec<f32> = decoder.convert_samples().collect();
+    Some(TTSResult { audio, sample_rate })
+}
+
+/// Persist `result` as a WAV file at `path`, creating the cache directory
+/// if needed. Best-effort: a write failure just means the next read misses
+/// the cache and re-synthesizes.
+fn store_to_cache(path: &Path, result: &TTSResult) {
+    if let Some(dir) = path.parent() {
+        if std::fs::create_dir_all(dir).is_err() {
+            return;
+        }
+    }
+    let _ = std::fs::write(path, result.to_wav());
+}
+
+/// Split `text` into sentence-sized units, trimming surrounding whitespace
+/// and dropping any that are empty.
+fn split_sentences(text: &str) -> Vec<String> {
+    text.split_inclusive(&['.', '!', '?'][..])
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
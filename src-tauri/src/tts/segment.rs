@@ -0,0 +1,175 @@
+//! Chapter/duration-segmented audiobook writer.
+//!
+//! Accepts streamed TTS audio and rolls it into separate files, either at
+//! chapter boundaries or once a segment exceeds a maximum duration or size,
+//! firing a callback with each completed file (mirroring biliup's
+//! `Segmentable` / `LifecycleFile` hook) so the frontend can build a chapter
+//! index and show progress.
+
+use crate::tts::kokoro::{AudioFormat, AudioMetadata, TTSResult};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SegmentError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Encoding error: {0}")]
+    EncodeError(String),
+}
+
+/// Information about a completed segment file, delivered to the callback.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SegmentInfo {
+    pub index: usize,
+    pub path: PathBuf,
+    pub chapter_title: String,
+    pub duration_secs: f64,
+    pub byte_size: u64,
+}
+
+/// Callback fired once per completed segment file.
+pub type SegmentCallback = Arc<dyn Fn(SegmentInfo) + Send + Sync>;
+
+/// Accumulates streamed audio and rolls it into segment files.
+pub struct SegmentWriter {
+    output_dir: PathBuf,
+    base_name: String,
+    format: AudioFormat,
+    sample_rate: u32,
+    /// Roll a new file once the current segment reaches this many seconds.
+    max_duration_secs: Option<f64>,
+    /// Roll a new file once the current segment reaches this many bytes of PCM.
+    max_pcm_bytes: Option<usize>,
+    on_segment: SegmentCallback,
+
+    segment_index: usize,
+    pending: Vec<f32>,
+    chapter_title: String,
+}
+
+impl SegmentWriter {
+    /// Create a writer that emits `format` files named `<base_name>-<n>` into
+    /// `output_dir`, invoking `on_segment` as each file is finalized.
+    pub fn new(
+        output_dir: impl AsRef<Path>,
+        base_name: impl Into<String>,
+        format: AudioFormat,
+        sample_rate: u32,
+        on_segment: SegmentCallback,
+    ) -> Self {
+        Self {
+            output_dir: output_dir.as_ref().to_path_buf(),
+            base_name: base_name.into(),
+            format,
+            sample_rate,
+            max_duration_secs: None,
+            max_pcm_bytes: None,
+            on_segment,
+            segment_index: 0,
+            pending: Vec::new(),
+            chapter_title: String::new(),
+        }
+    }
+
+    /// Roll a new file once a segment exceeds `secs` seconds of audio.
+    pub fn with_max_duration(mut self, secs: f64) -> Self {
+        self.max_duration_secs = Some(secs);
+        self
+    }
+
+    /// Roll a new file once a segment exceeds `bytes` of 16-bit PCM.
+    pub fn with_max_size(mut self, bytes: usize) -> Self {
+        self.max_pcm_bytes = Some(bytes);
+        self
+    }
+
+    /// Feed a decoded audio block belonging to `chapter_title`. Splits the file
+    /// when the chapter changes or a size/duration threshold is crossed.
+    pub fn feed(&mut self, result: &TTSResult, chapter_title: &str) -> Result<(), SegmentError> {
+        // A change of chapter always starts a fresh segment.
+        if !self.pending.is_empty() && chapter_title != self.chapter_title {
+            self.flush()?;
+        }
+        self.chapter_title = chapter_title.to_string();
+        self.pending.extend_from_slice(&result.audio);
+
+        if self.threshold_reached() {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Force the current buffered audio out to a file at a chapter boundary.
+    pub fn mark_chapter_boundary(&mut self) -> Result<(), SegmentError> {
+        self.flush()
+    }
+
+    /// Write any remaining buffered audio as a final segment.
+    pub fn finish(mut self) -> Result<(), SegmentError> {
+        self.flush()
+    }
+
+    fn threshold_reached(&self) -> bool {
+        if let Some(max_bytes) = self.max_pcm_bytes {
+            if self.pending.len() * 2 >= max_bytes {
+                return true;
+            }
+        }
+        if let Some(max_secs) = self.max_duration_secs {
+            if self.pending.len() as f64 / self.sample_rate as f64 >= max_secs {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Encode the pending buffer to a numbered file and notify the callback.
+    fn flush(&mut self) -> Result<(), SegmentError> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let samples = std::mem::take(&mut self.pending);
+        let duration_secs = samples.len() as f64 / self.sample_rate as f64;
+        let result = TTSResult {
+            audio: samples,
+            sample_rate: self.sample_rate,
+        };
+
+        let metadata = AudioMetadata {
+            chapter: Some(self.chapter_title.clone()),
+            track: Some(self.segment_index as u32 + 1),
+            ..AudioMetadata::default()
+        };
+        let bytes = result
+            .encode(self.format, &metadata)
+            .map_err(|e| SegmentError::EncodeError(e.to_string()))?;
+
+        std::fs::create_dir_all(&self.output_dir)?;
+        let extension = match self.format {
+            AudioFormat::Wav => "wav",
+            AudioFormat::Mp3 => "mp3",
+            AudioFormat::Ogg => "ogg",
+        };
+        let path = self.output_dir.join(format!(
+            "{}-{:03}.{}",
+            self.base_name,
+            self.segment_index + 1,
+            extension
+        ));
+        std::fs::write(&path, &bytes)?;
+
+        (self.on_segment)(SegmentInfo {
+            index: self.segment_index,
+            path: path.clone(),
+            chapter_title: self.chapter_title.clone(),
+            duration_secs,
+            byte_size: bytes.len() as u64,
+        });
+
+        self.segment_index += 1;
+        Ok(())
+    }
+}
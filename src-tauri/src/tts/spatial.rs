@@ -0,0 +1,362 @@
+//! Optional HRTF binaural rendering stage.
+//!
+//! Convolves a mono source against a head-related impulse response (HRIR)
+//! so it's perceived as coming from a chosen azimuth/elevation -- the same
+//! HRIR-convolution technique as gstreamer's `hrtfrender` element. Audio is
+//! processed in fixed-size blocks via FFT overlap-add, with the overlap
+//! ("future" samples the current block's convolution spills into) carried
+//! forward between blocks, so render latency is bounded by `BLOCK_SIZE`
+//! regardless of how long the impulse response is.
+
+use rodio::Source;
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SpatialError {
+    #[error("Failed to read HRIR directory: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Failed to decode HRIR impulse response: {0}")]
+    DecodeError(String),
+    #[error("No HRIR directions found under {0}")]
+    NoDirections(PathBuf),
+}
+
+/// Requested spatial placement for a session's narration. `hrir_path` points
+/// at a directory of WAV pairs named `<azimuth>_<elevation>_L.wav` /
+/// `_R.wav` (the common layout HRIR sets are distributed in, and what a
+/// SOFA file would be converted to on load).
+#[derive(Debug, Clone)]
+pub struct SpatialConfig {
+    /// Horizontal angle in degrees: 0 = straight ahead, positive = right.
+    pub azimuth: f32,
+    /// Vertical angle in degrees: 0 = ear level, positive = up.
+    pub elevation: f32,
+    pub hrir_path: PathBuf,
+}
+
+/// Fixed input block size, in samples. Bounds the stage's latency: audio
+/// only has to buffer this many samples before binaural output starts
+/// flowing, no matter how long the HRIR or the overall clip is.
+const BLOCK_SIZE: usize = 1024;
+
+/// A single direction's stereo impulse response, resampled to the output
+/// sample rate and pre-transformed into the frequency domain.
+struct HrirDirection {
+    azimuth: f32,
+    elevation: f32,
+    left_freq: Vec<Complex<f32>>,
+    right_freq: Vec<Complex<f32>>,
+}
+
+/// A loaded HRIR set, ready to pick the nearest direction and build a
+/// [`BinauralSource`] against it.
+pub struct HrirSet {
+    fft_len: usize,
+    directions: Vec<HrirDirection>,
+}
+
+impl HrirSet {
+    /// Load every `<az>_<el>_L.wav` / `_R.wav` pair under `path`, resampling
+    /// each impulse response to `target_rate`.
+    pub fn load(path: &Path, target_rate: u32) -> Result<Self, SpatialError> {
+        let mut raw = Vec::new();
+        let mut max_taps = 0usize;
+
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy().into_owned();
+            let Some(base) = name.strip_suffix("_L.wav") else {
+                continue;
+            };
+            let Some((azimuth, elevation)) = parse_direction(base) else {
+                continue;
+            };
+
+            let (left, left_rate) = decode_mono_wav(&path.join(format!("{base}_L.wav")))?;
+            let (right, right_rate) = decode_mono_wav(&path.join(format!("{base}_R.wav")))?;
+            let left = resample_linear(&left, left_rate, target_rate);
+            let right = resample_linear(&right, right_rate, target_rate);
+
+            max_taps = max_taps.max(left.len()).max(right.len());
+            raw.push((azimuth, elevation, left, right));
+        }
+
+        if raw.is_empty() {
+            return Err(SpatialError::NoDirections(path.to_path_buf()));
+        }
+
+        // One FFT size serves every direction and every block: large enough
+        // for the longest impulse response found, fixed for the set's
+        // lifetime so every `BinauralSource` built from it uses the same
+        // FFT length.
+        let fft_len = (BLOCK_SIZE + max_taps - 1).next_power_of_two();
+        let mut planner = FftPlanner::<f32>::new();
+        let fft = planner.plan_fft_forward(fft_len);
+
+        let directions = raw
+            .into_iter()
+            .map(|(azimuth, elevation, left, right)| HrirDirection {
+                azimuth,
+                elevation,
+                left_freq: transform_ir(fft.as_ref(), &left, fft_len),
+                right_freq: transform_ir(fft.as_ref(), &right, fft_len),
+            })
+            .collect();
+
+        Ok(Self { fft_len, directions })
+    }
+
+    /// The direction whose azimuth/elevation is angularly closest to the
+    /// requested one.
+    fn nearest(&self, azimuth: f32, elevation: f32) -> &HrirDirection {
+        self.directions
+            .iter()
+            .min_by(|a, b| {
+                let da = angular_distance(azimuth, elevation, a.azimuth, a.elevation);
+                let db = angular_distance(azimuth, elevation, b.azimuth, b.elevation);
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .expect("load() rejects empty HRIR sets")
+    }
+
+    /// Wrap `inner` (a mono source) so it plays back rendered at
+    /// `(azimuth, elevation)` against this set's nearest matching impulse
+    /// response.
+    pub fn render<S>(&self, inner: S, azimuth: f32, elevation: f32) -> BinauralSource<S>
+    where
+        S: Source<Item = f32>,
+    {
+        let direction = self.nearest(azimuth, elevation);
+        let mut planner = FftPlanner::<f32>::new();
+        let fft = planner.plan_fft_forward(self.fft_len);
+        let ifft = planner.plan_fft_inverse(self.fft_len);
+
+        BinauralSource {
+            sample_rate: inner.sample_rate(),
+            inner,
+            fft,
+            ifft,
+            fft_len: self.fft_len,
+            left_freq: direction.left_freq.clone(),
+            right_freq: direction.right_freq.clone(),
+            overlap_left: vec![0.0; self.fft_len],
+            overlap_right: vec![0.0; self.fft_len],
+            out_left: Vec::new(),
+            out_right: Vec::new(),
+            out_pos: 0,
+            emit_right_next: false,
+            source_exhausted: false,
+            flushed_silence: false,
+        }
+    }
+}
+
+/// A rodio `Source` that renders its (mono) inner source binaurally,
+/// producing interleaved stereo samples.
+pub struct BinauralSource<S> {
+    inner: S,
+    sample_rate: u32,
+    fft: Arc<dyn Fft<f32>>,
+    ifft: Arc<dyn Fft<f32>>,
+    fft_len: usize,
+    left_freq: Vec<Complex<f32>>,
+    right_freq: Vec<Complex<f32>>,
+    /// Overlap-add state: samples from past blocks' convolutions that spill
+    /// past the current block, carried forward and added into the next one.
+    overlap_left: Vec<f32>,
+    overlap_right: Vec<f32>,
+    out_left: Vec<f32>,
+    out_right: Vec<f32>,
+    out_pos: usize,
+    emit_right_next: bool,
+    source_exhausted: bool,
+    /// Silent blocks are rendered after the inner source ends until the
+    /// overlap tail is fully flushed; this is set then, and playback ends.
+    flushed_silence: bool,
+}
+
+impl<S: Source<Item = f32>> BinauralSource<S> {
+    /// Render the next `BLOCK_SIZE`-sample block, or return `false` once
+    /// there's nothing left to flush.
+    fn render_next_block(&mut self) -> bool {
+        if self.source_exhausted && self.flushed_silence {
+            return false;
+        }
+
+        let mut block = vec![0.0f32; self.fft_len];
+        let mut got_samples = false;
+        for slot in block.iter_mut().take(BLOCK_SIZE) {
+            match self.inner.next() {
+                Some(sample) => {
+                    *slot = sample;
+                    got_samples = true;
+                }
+                None => {
+                    self.source_exhausted = true;
+                    break;
+                }
+            }
+        }
+        let mut spectrum: Vec<Complex<f32>> =
+            block.iter().map(|&s| Complex::new(s, 0.0)).collect();
+        self.fft.process(&mut spectrum);
+
+        self.out_left = overlap_add_block(
+            self.ifft.as_ref(),
+            &spectrum,
+            &self.left_freq,
+            &mut self.overlap_left,
+            self.fft_len,
+        );
+        self.out_right = overlap_add_block(
+            self.ifft.as_ref(),
+            &spectrum,
+            &self.right_freq,
+            &mut self.overlap_right,
+            self.fft_len,
+        );
+        // Done once a silent block leaves no overlap tail to carry forward.
+        if self.source_exhausted
+            && !got_samples
+            && self.overlap_left.iter().chain(&self.overlap_right).all(|&s| s == 0.0)
+        {
+            self.flushed_silence = true;
+        }
+        self.out_pos = 0;
+        true
+    }
+}
+
+impl<S: Source<Item = f32>> Iterator for BinauralSource<S> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.emit_right_next {
+            self.emit_right_next = false;
+            let sample = self.out_right[self.out_pos];
+            self.out_pos += 1;
+            return Some(sample);
+        }
+
+        if self.out_pos >= self.out_left.len() && !self.render_next_block() {
+            return None;
+        }
+
+        let sample = self.out_left[self.out_pos];
+        self.emit_right_next = true;
+        Some(sample)
+    }
+}
+
+impl<S: Source<Item = f32>> Source for BinauralSource<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        2
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// FFT `spectrum` (the current block, already transformed) against
+/// `ir_freq` (one ear's pre-transformed impulse response), inverse-transform
+/// it, add the carried-over `overlap`, and shift `overlap` forward by one
+/// block for next time.
+fn overlap_add_block(
+    ifft: &dyn Fft<f32>,
+    spectrum: &[Complex<f32>],
+    ir_freq: &[Complex<f32>],
+    overlap: &mut Vec<f32>,
+    fft_len: usize,
+) -> Vec<f32> {
+    let mut product: Vec<Complex<f32>> = spectrum
+        .iter()
+        .zip(ir_freq.iter())
+        .map(|(a, b)| a * b)
+        .collect();
+    ifft.process(&mut product);
+
+    let scale = 1.0 / fft_len as f32;
+    for (i, sample) in overlap.iter_mut().enumerate() {
+        *sample += product[i].re * scale;
+    }
+
+    let output: Vec<f32> = overlap.drain(0..BLOCK_SIZE.min(overlap.len())).collect();
+    overlap.resize(fft_len, 0.0);
+    output
+}
+
+/// Zero-pad `ir` to `fft_len` and transform it, ready to be multiplied
+/// against a block spectrum each time it's used.
+fn transform_ir(fft: &dyn Fft<f32>, ir: &[f32], fft_len: usize) -> Vec<Complex<f32>> {
+    let mut spectrum: Vec<Complex<f32>> = ir.iter().map(|&s| Complex::new(s, 0.0)).collect();
+    spectrum.resize(fft_len, Complex::new(0.0, 0.0));
+    fft.process(&mut spectrum);
+    spectrum
+}
+
+/// Parse a `<azimuth>_<elevation>` file stem into its two angles.
+fn parse_direction(stem: &str) -> Option<(f32, f32)> {
+    let mut parts = stem.splitn(2, '_');
+    let azimuth: f32 = parts.next()?.parse().ok()?;
+    let elevation: f32 = parts.next()?.parse().ok()?;
+    Some((azimuth, elevation))
+}
+
+fn angular_distance(az_a: f32, el_a: f32, az_b: f32, el_b: f32) -> f32 {
+    (az_a - az_b).powi(2) + (el_a - el_b).powi(2)
+}
+
+/// Decode a (mono) WAV file to PCM plus its native sample rate.
+fn decode_mono_wav(path: &Path) -> Result<(Vec<f32>, u32), SpatialError> {
+    let bytes = fs::read(path)?;
+    let decoder = rodio::Decoder::new(std::io::Cursor::new(bytes))
+        .map_err(|e| SpatialError::DecodeError(e.to_string()))?;
+    let sample_rate = decoder.sample_rate();
+    let channels = decoder.channels().max(1) as usize;
+    let samples: Vec<f32> = decoder.convert_samples().collect();
+    if channels <= 1 {
+        return Ok((samples, sample_rate));
+    }
+    let mono = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect();
+    Ok((mono, sample_rate))
+}
+
+/// Naive linear-interpolation resample, good enough for aligning an HRIR's
+/// native rate to the output stream's rate without pulling in a dedicated
+/// resampling dependency.
+fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let ratio = to_rate as f64 / from_rate as f64;
+    let out_len = ((samples.len() as f64) * ratio).round() as usize;
+    (0..out_len)
+        .map(|i| {
+            let src_pos = i as f64 / ratio;
+            let idx = src_pos.floor() as usize;
+            let frac = (src_pos - idx as f64) as f32;
+            let a = samples.get(idx).copied().unwrap_or(0.0);
+            let b = samples.get(idx + 1).copied().unwrap_or(a);
+            a + (b - a) * frac
+        })
+        .collect()
+}
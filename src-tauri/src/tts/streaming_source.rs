@@ -3,64 +3,477 @@
 //! Receives f32 audio samples progressively via a crossbeam channel
 //! and implements `rodio::Source` for direct sink playback.
 
-use crossbeam_channel::Receiver;
+use crossbeam_channel::{Receiver, Sender};
 use rodio::Source;
 use std::collections::VecDeque;
-use std::time::Duration;
-
-/// Minimum samples to buffer before starting playback (~5 seconds at 24kHz).
-/// This prevents stuttering when the generator is slower than playback.
-const MIN_BUFFER_SAMPLES: usize = 120000;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Timeout for blocking receive when buffer is low (ms).
 const BUFFER_FILL_TIMEOUT_MS: u64 = 200;
 
+/// Fallback drain-burst estimate before any samples have actually been
+/// drained (~20ms at 48kHz, ~40ms at 24kHz).
+const DEFAULT_PERIOD_SAMPLES: f64 = 960.0;
+/// The period estimate never shrinks below this, so a couple of unusually
+/// small bursts can't thrash the target on every call.
+const MIN_PERIOD_SAMPLES: f64 = 256.0;
+/// Multiplicative growth applied to the jitter buffer target each time the
+/// consumer catches up with the producer and we're forced to emit silence.
+const UNDERRUN_GROWTH_FACTOR: f64 = 1.5;
+/// Multiplicative decay applied to the target after a run of clean drains,
+/// so a one-off stall doesn't permanently inflate the buffer.
+const TARGET_DECAY_FACTOR: f64 = 0.98;
+/// How many cleanly-drained samples pass between each decay step.
+const DECAY_INTERVAL_SAMPLES: usize = 4800;
+/// Smoothing factor for the arrival-interval and drain-burst EMAs.
+const EMA_ALPHA: f64 = 0.2;
+/// Upper bound on the jitter buffer target, so a burst of underruns can't
+/// inflate it without limit.
+const MAX_TARGET_SECONDS: u64 = 10;
+
+/// How many seconds of already-played samples to retain for instant
+/// rewinds before a seek has to fall back to re-synthesis.
+const BACK_BUFFER_SECONDS: u64 = 30;
+
+fn ema_update(current: &mut Option<f64>, sample: f64) {
+    *current = Some(match *current {
+        Some(prev) => EMA_ALPHA * sample + (1.0 - EMA_ALPHA) * prev,
+        None => sample,
+    });
+}
+
+/// Telemetry handle for `StreamingSource`'s adaptive jitter buffer. Clone it
+/// out before the Source is handed off to a sink/backend (same pattern as
+/// `samples_played_handle`) so the UI can poll playback health from a
+/// thread that no longer has access to the Source itself.
+#[derive(Clone)]
+pub struct JitterBufferStats {
+    underruns: Arc<AtomicUsize>,
+    silence_samples_emitted: Arc<AtomicUsize>,
+    current_target_samples: Arc<AtomicUsize>,
+}
+
+impl JitterBufferStats {
+    /// Number of times the buffer ran dry and a silence sample was forced.
+    pub fn underruns(&self) -> usize {
+        self.underruns.load(Ordering::Relaxed)
+    }
+
+    /// Total silence samples emitted to paper over underruns so far.
+    pub fn silence_samples_emitted(&self) -> usize {
+        self.silence_samples_emitted.load(Ordering::Relaxed)
+    }
+
+    /// Current adaptive buffer depth target, in samples.
+    pub fn current_target_samples(&self) -> usize {
+        self.current_target_samples.load(Ordering::Relaxed)
+    }
+}
+
+/// A command sent to a `StreamingSource`'s loader side-channel. `next()`
+/// polls for these opportunistically, the same way it opportunistically
+/// drains the audio channel via `try_fill_buffer`.
+#[derive(Debug, Clone)]
+pub enum LoaderCommand {
+    /// Jump playback to this sample offset.
+    Seek(usize),
+    /// Hint that this sample range will likely be needed soon. Honored by
+    /// the `StreamingSource` only as a log for now -- none of this crate's
+    /// generators currently support synthesizing out of sequence, so
+    /// there's nothing to prioritize yet; the hint exists so a future
+    /// non-sequential generator has somewhere to receive it.
+    Prefetch(Range<usize>),
+}
+
+/// Sent back once a `Seek` has been serviced and the buffer has enough
+/// samples around the target for smooth playback to resume. Carries the
+/// offset actually landed on, which can be clamped forward of the
+/// requested offset if it predates the retained back-buffer.
+#[derive(Debug, Clone, Copy)]
+struct SeekAck {
+    landed_at: usize,
+}
+
+/// UI-facing handle for seeking/scrubbing a `StreamingSource` during
+/// playback. Holds one end of a command channel the source polls from
+/// `next()`, the same command-channel shape `PlaybackManager` uses to
+/// drive its owning thread.
+#[derive(Clone)]
+pub struct StreamLoaderController {
+    tx: Sender<LoaderCommand>,
+    ack_rx: Receiver<SeekAck>,
+}
+
+impl StreamLoaderController {
+    /// Request a jump to `sample_offset` and return immediately. The
+    /// source services it the next time it polls its command channel, at
+    /// latest on the next sample it produces.
+    pub fn seek(&self, sample_offset: usize) {
+        let _ = self.tx.send(LoaderCommand::Seek(sample_offset));
+    }
+
+    /// Hint that `range` will likely be needed soon.
+    pub fn prefetch(&self, range: Range<usize>) {
+        let _ = self.tx.send(LoaderCommand::Prefetch(range));
+    }
+
+    /// Request a jump to `sample_offset` and block until the source has
+    /// re-buffered enough samples around the target to resume smoothly,
+    /// analogous to `EchoManager::generate` blocking for a synthesis
+    /// result. Returns the offset actually landed on (see `SeekAck`), or
+    /// `None` if the source didn't acknowledge within `timeout`.
+    pub fn seek_blocking(&self, sample_offset: usize, timeout: Duration) -> Option<usize> {
+        // Drop any stale ack left over from a previous seek.
+        while self.ack_rx.try_recv().is_ok() {}
+        self.seek(sample_offset);
+        self.ack_rx
+            .recv_timeout(timeout)
+            .ok()
+            .map(|ack| ack.landed_at)
+    }
+}
+
 /// A rodio Source that receives f32 audio samples progressively via a channel.
 ///
 /// Buffers audio samples before starting playback to prevent stuttering.
 /// When the channel is empty but still open (generator slower than playback),
 /// yields silence. When the channel is closed and all buffered samples are
 /// consumed, returns None.
+///
+/// The buffering target is adaptive rather than a fixed constant: it tracks
+/// an EMA of inter-chunk arrival intervals and of how many samples get
+/// drained between channel reads (a proxy for the output device's period
+/// size), aims for roughly `max(arrival_jitter * sample_rate, one_period)`,
+/// and nudges that target up on every underrun and back down over sustained
+/// clean playback -- similar to how an ALSA sink always delivers a full
+/// period rather than partial fills, but sized from measured behavior
+/// instead of a guess.
 pub struct StreamingSource {
     rx: Receiver<Vec<f32>>,
     buffer: VecDeque<f32>,
     sample_rate: u32,
+    channels: u16,
     finished: bool,
     initial_buffer_filled: bool,
+    /// Running count of samples yielded to the consumer so far. Shared via
+    /// `samples_played_handle` so something outside the Source (e.g. a
+    /// `Timeline` in the playback loop) can map live playback position back
+    /// to a word index without owning the Source itself.
+    samples_played: Arc<AtomicUsize>,
+
+    /// EMA of the gap between successive chunk arrivals on the channel.
+    ema_arrival_interval_secs: Option<f64>,
+    last_arrival: Option<Instant>,
+    /// EMA of how many samples get drained between channel reads -- a
+    /// proxy for the output device's period size.
+    ema_period_samples: Option<f64>,
+    /// Samples drained from the buffer since the last channel read.
+    since_boundary: usize,
+    /// Samples drained cleanly (no underrun) since the last decay step.
+    clean_since_decay: usize,
+    /// Multiplier applied on top of the EMA-derived baseline target. Grows
+    /// on underrun, decays over sustained clean playback, floor of 1.0.
+    growth_multiplier: f64,
+
+    underruns: Arc<AtomicUsize>,
+    silence_samples_emitted: Arc<AtomicUsize>,
+    current_target_samples: Arc<AtomicUsize>,
+
+    /// Ring buffer of the most recently yielded samples, for instant
+    /// rewinds without re-synthesis.
+    back_buffer: VecDeque<f32>,
+    back_buffer_capacity: usize,
+    /// Seek target we're fast-forwarding towards by discarding incoming
+    /// samples, set when a seek landed outside the retained window.
+    pending_seek: Option<usize>,
+    commands_rx: Receiver<LoaderCommand>,
+    ack_tx: Sender<SeekAck>,
 }
 
 impl StreamingSource {
+    /// Mono source (e.g. the Mimi codec used by Echo-1B).
     pub fn new(rx: Receiver<Vec<f32>>, sample_rate: u32) -> Self {
+        Self::with_channels(rx, sample_rate, 1)
+    }
+
+    /// Source with an explicit channel count, for generators (e.g. the Python
+    /// streaming bridge) that can report stereo or other layouts.
+    pub fn with_channels(rx: Receiver<Vec<f32>>, sample_rate: u32, channels: u16) -> Self {
+        // Not seekable from outside: these two ends are simply dropped, so
+        // `commands_rx`/`ack_tx` behave as permanently-empty/disconnected.
+        let (_tx, commands_rx) = crossbeam_channel::unbounded();
+        let (ack_tx, _ack_rx) = crossbeam_channel::unbounded();
+        Self::new_inner(rx, sample_rate, channels, commands_rx, ack_tx)
+    }
+
+    /// Seekable mono source, paired with a `StreamLoaderController` the UI
+    /// can use to scrub playback.
+    pub fn seekable(rx: Receiver<Vec<f32>>, sample_rate: u32) -> (Self, StreamLoaderController) {
+        Self::seekable_with_channels(rx, sample_rate, 1)
+    }
+
+    /// Seekable source with an explicit channel count.
+    pub fn seekable_with_channels(
+        rx: Receiver<Vec<f32>>,
+        sample_rate: u32,
+        channels: u16,
+    ) -> (Self, StreamLoaderController) {
+        let (tx, commands_rx) = crossbeam_channel::unbounded();
+        let (ack_tx, ack_rx) = crossbeam_channel::unbounded();
+        let source = Self::new_inner(rx, sample_rate, channels, commands_rx, ack_tx);
+        (source, StreamLoaderController { tx, ack_rx })
+    }
+
+    fn new_inner(
+        rx: Receiver<Vec<f32>>,
+        sample_rate: u32,
+        channels: u16,
+        commands_rx: Receiver<LoaderCommand>,
+        ack_tx: Sender<SeekAck>,
+    ) -> Self {
         Self {
             rx,
-            buffer: VecDeque::with_capacity(MIN_BUFFER_SAMPLES * 2), // Room for ~4 seconds
+            buffer: VecDeque::with_capacity(sample_rate as usize * 2),
             sample_rate,
+            channels: channels.max(1),
             finished: false,
             initial_buffer_filled: false,
+            samples_played: Arc::new(AtomicUsize::new(0)),
+            ema_arrival_interval_secs: None,
+            last_arrival: None,
+            ema_period_samples: None,
+            since_boundary: 0,
+            clean_since_decay: 0,
+            growth_multiplier: 1.0,
+            underruns: Arc::new(AtomicUsize::new(0)),
+            silence_samples_emitted: Arc::new(AtomicUsize::new(0)),
+            current_target_samples: Arc::new(AtomicUsize::new(DEFAULT_PERIOD_SAMPLES as usize)),
+            back_buffer: VecDeque::new(),
+            back_buffer_capacity: sample_rate as usize * BACK_BUFFER_SECONDS as usize,
+            pending_seek: None,
+            commands_rx,
+            ack_tx,
+        }
+    }
+
+    /// A handle tracking how many samples have been pulled from this Source
+    /// so far. Clone it before handing the Source off to a sink/backend --
+    /// once appended, the Source itself is no longer reachable.
+    pub fn samples_played_handle(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.samples_played)
+    }
+
+    /// A handle onto this Source's jitter buffer telemetry. Clone it before
+    /// handing the Source off to a sink/backend.
+    pub fn jitter_stats_handle(&self) -> JitterBufferStats {
+        JitterBufferStats {
+            underruns: Arc::clone(&self.underruns),
+            silence_samples_emitted: Arc::clone(&self.silence_samples_emitted),
+            current_target_samples: Arc::clone(&self.current_target_samples),
+        }
+    }
+
+    /// Record that a chunk just arrived, updating the arrival-interval EMA.
+    fn record_arrival(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.last_arrival {
+            ema_update(
+                &mut self.ema_arrival_interval_secs,
+                now.duration_since(last).as_secs_f64(),
+            );
         }
+        self.last_arrival = Some(now);
+    }
+
+    /// Recompute `current_target_samples` from the current EMAs and growth
+    /// multiplier: `max(arrival_jitter * sample_rate, one_period)`, scaled
+    /// by the multiplier, clamped to `[one_period, MAX_TARGET_SECONDS]`.
+    fn recompute_target(&mut self) {
+        let one_period = self
+            .ema_period_samples
+            .unwrap_or(DEFAULT_PERIOD_SAMPLES)
+            .max(MIN_PERIOD_SAMPLES);
+        let jitter_samples =
+            self.ema_arrival_interval_secs.unwrap_or(0.0) * self.sample_rate as f64;
+        let baseline = jitter_samples.max(one_period);
+        let max_target = self.sample_rate as f64 * MAX_TARGET_SECONDS as f64;
+        let target = (baseline * self.growth_multiplier).clamp(one_period, max_target);
+        self.current_target_samples
+            .store(target.round() as usize, Ordering::Relaxed);
+    }
+
+    /// A sample was drained without needing to force silence: advance the
+    /// clean-streak counter and decay the growth multiplier once it's run
+    /// long enough that the earlier underrun looks like a one-off.
+    fn note_clean_drain(&mut self) {
+        self.clean_since_decay += 1;
+        if self.clean_since_decay >= DECAY_INTERVAL_SAMPLES {
+            self.clean_since_decay = 0;
+            self.growth_multiplier = (self.growth_multiplier * TARGET_DECAY_FACTOR).max(1.0);
+            self.recompute_target();
+        }
+    }
+
+    /// Append a just-consumed sample to the back-buffer, evicting the
+    /// oldest one if it's grown past capacity.
+    fn push_back_buffer(&mut self, sample: f32) {
+        self.back_buffer.push_back(sample);
+        if self.back_buffer.len() > self.back_buffer_capacity {
+            self.back_buffer.pop_front();
+        }
+    }
+
+    fn ack(&self, landed_at: usize) {
+        let _ = self.ack_tx.send(SeekAck { landed_at });
+    }
+
+    /// Drain any pending loader commands, opportunistically -- the same way
+    /// `try_fill_buffer` opportunistically drains the audio channel.
+    fn poll_commands(&mut self) {
+        while let Ok(cmd) = self.commands_rx.try_recv() {
+            match cmd {
+                LoaderCommand::Seek(target) => self.handle_seek(target),
+                LoaderCommand::Prefetch(range) => {
+                    eprintln!(
+                        "[StreamingSource] Prefetch hint for samples {}..{}",
+                        range.start, range.end
+                    );
+                }
+            }
+        }
+    }
+
+    /// Service a seek request. Short rewinds within the retained
+    /// back-buffer and forward jumps within the already-buffered forward
+    /// audio resolve instantly. Anything further out flushes both buffers
+    /// and falls back to re-synthesis: since every generator in this crate
+    /// produces audio strictly in order, "signaling" it with the requested
+    /// range means fast-forwarding by discarding incoming samples until we
+    /// reach it (see `discard_to`) -- a backward jump past the retained
+    /// window can't be recovered at all without restarting synthesis, so
+    /// it's clamped to the oldest sample we still have.
+    fn handle_seek(&mut self, requested: usize) {
+        let position = self.samples_played.load(Ordering::SeqCst);
+        let earliest_retained = position.saturating_sub(self.back_buffer.len());
+        let target = requested.max(earliest_retained);
+        if requested < earliest_retained {
+            eprintln!(
+                "[StreamingSource] Seek to {} predates the retained back-buffer (earliest: {}); clamping",
+                requested, earliest_retained
+            );
+        }
+
+        if target <= position {
+            let rewind = position - target;
+            if rewind > 0 {
+                let start = self.back_buffer.len() - rewind;
+                let mut restored: VecDeque<f32> = self.back_buffer.drain(start..).collect();
+                restored.append(&mut self.buffer);
+                self.buffer = restored;
+                self.samples_played.fetch_sub(rewind, Ordering::SeqCst);
+            }
+            self.ack(target);
+            return;
+        }
+
+        let forward = target - position;
+        if forward <= self.buffer.len() {
+            for _ in 0..forward {
+                if let Some(sample) = self.buffer.pop_front() {
+                    self.push_back_buffer(sample);
+                }
+            }
+            self.samples_played.fetch_add(forward, Ordering::SeqCst);
+            self.ack(target);
+            return;
+        }
+
+        // Beyond what's buffered: flush and fast-forward on the next fill.
+        self.buffer.clear();
+        self.pending_seek = Some(target);
+        self.initial_buffer_filled = false;
+    }
+
+    /// Discard incoming samples from the (strictly sequential) generator
+    /// until playback position reaches `target`, banking each one into the
+    /// back-buffer as it goes so an immediate rewind past the target still
+    /// works, then keep buffering until the adaptive target is met before
+    /// acknowledging the seek.
+    fn discard_to(&mut self, target: usize) {
+        eprintln!(
+            "[StreamingSource] Seeking forward to sample {} by discarding incoming audio...",
+            target
+        );
+        loop {
+            if self.samples_played.load(Ordering::SeqCst) >= target
+                && self.buffer.len() >= self.current_target_samples.load(Ordering::Relaxed)
+            {
+                break;
+            }
+            match self
+                .rx
+                .recv_timeout(Duration::from_millis(BUFFER_FILL_TIMEOUT_MS))
+            {
+                Ok(samples) => {
+                    self.record_arrival();
+                    for sample in samples {
+                        if self.samples_played.load(Ordering::SeqCst) >= target {
+                            // Already at/past the target -- this belongs to
+                            // the audio we actually want to play.
+                            self.buffer.push_back(sample);
+                        } else {
+                            self.push_back_buffer(sample);
+                            self.samples_played.fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                }
+                Err(crossbeam_channel::RecvTimeoutError::Timeout) => continue,
+                Err(crossbeam_channel::RecvTimeoutError::Disconnected) => {
+                    eprintln!(
+                        "[StreamingSource] Generator ended before reaching seek target {}",
+                        target
+                    );
+                    break;
+                }
+            }
+        }
+        self.pending_seek = None;
+        self.ack(self.samples_played.load(Ordering::SeqCst));
     }
 
     /// Non-blocking drain of all available chunks from the channel.
     fn try_fill_buffer(&mut self) {
         while let Ok(samples) = self.rx.try_recv() {
             self.buffer.extend(samples);
+            self.record_arrival();
         }
+        self.recompute_target();
     }
 
-    /// Block until we have enough samples buffered for smooth playback.
+    /// Block until we have enough samples buffered for smooth playback,
+    /// using the current adaptive target rather than a fixed constant.
     fn fill_initial_buffer(&mut self) {
+        if let Some(target) = self.pending_seek {
+            self.discard_to(target);
+        }
+
         eprintln!(
             "[StreamingSource] Filling initial buffer (target: {} samples)...",
-            MIN_BUFFER_SAMPLES
+            self.current_target_samples.load(Ordering::Relaxed)
         );
 
-        while self.buffer.len() < MIN_BUFFER_SAMPLES {
+        while self.buffer.len() < self.current_target_samples.load(Ordering::Relaxed) {
             match self
                 .rx
                 .recv_timeout(Duration::from_millis(BUFFER_FILL_TIMEOUT_MS))
             {
                 Ok(samples) => {
                     self.buffer.extend(samples);
+                    self.record_arrival();
+                    self.recompute_target();
                 }
                 Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
                     // Keep waiting - generator is slow
@@ -84,16 +497,14 @@ impl StreamingSource {
             self.buffer.len() as f64 / self.sample_rate as f64
         );
     }
-}
 
-impl Iterator for StreamingSource {
-    type Item = f32;
-
-    fn next(&mut self) -> Option<f32> {
+    fn next_sample(&mut self) -> Option<f32> {
         if self.finished {
             return None;
         }
 
+        self.poll_commands();
+
         // Fill initial buffer before yielding any audio
         if !self.initial_buffer_filled {
             self.fill_initial_buffer();
@@ -101,25 +512,47 @@ impl Iterator for StreamingSource {
 
         // Try buffer first
         if let Some(sample) = self.buffer.pop_front() {
+            self.since_boundary += 1;
+            self.note_clean_drain();
             // Opportunistically fill buffer while playing
             self.try_fill_buffer();
             return Some(sample);
         }
 
-        // Buffer empty -- try to receive more with a longer timeout
+        // Buffer empty -- this is a period boundary. Fold how many samples
+        // were drained since the last one into the period-length estimate.
+        let burst = self.since_boundary;
+        self.since_boundary = 0;
+        ema_update(&mut self.ema_period_samples, burst as f64);
+
+        // Try to receive more with a longer timeout
         match self.rx.recv_timeout(Duration::from_millis(100)) {
             Ok(samples) => {
+                self.record_arrival();
                 self.buffer.extend(samples);
-                self.buffer.pop_front()
+                self.recompute_target();
+                let sample = self.buffer.pop_front();
+                if sample.is_some() {
+                    self.since_boundary += 1;
+                    self.note_clean_drain();
+                }
+                sample
             }
             Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
-                // Generator is slower than playback -- yield silence
+                // Generator is slower than playback -- yield silence, and
+                // grow the target so this is less likely next time.
+                self.underruns.fetch_add(1, Ordering::Relaxed);
+                self.silence_samples_emitted.fetch_add(1, Ordering::Relaxed);
+                self.clean_since_decay = 0;
+                self.growth_multiplier *= UNDERRUN_GROWTH_FACTOR;
+                self.recompute_target();
                 Some(0.0)
             }
             Err(crossbeam_channel::RecvTimeoutError::Disconnected) => {
                 // Stream ended -- drain any remaining buffered data
                 self.try_fill_buffer();
                 if let Some(sample) = self.buffer.pop_front() {
+                    self.since_boundary += 1;
                     Some(sample)
                 } else {
                     self.finished = true;
@@ -130,13 +563,26 @@ impl Iterator for StreamingSource {
     }
 }
 
+impl Iterator for StreamingSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let sample = self.next_sample();
+        if let Some(s) = sample {
+            self.samples_played.fetch_add(1, Ordering::SeqCst);
+            self.push_back_buffer(s);
+        }
+        sample
+    }
+}
+
 impl Source for StreamingSource {
     fn current_frame_len(&self) -> Option<usize> {
         None // Unknown length (streaming)
     }
 
     fn channels(&self) -> u16 {
-        1 // Mono (Mimi codec outputs mono 24kHz)
+        self.channels
     }
 
     fn sample_rate(&self) -> u32 {
@@ -147,3 +593,102 @@ impl Source for StreamingSource {
         None // Unknown (streaming)
     }
 }
+
+#[cfg(test)]
+mod jitter_buffer_tests {
+    use super::*;
+
+    fn source(sample_rate: u32) -> StreamingSource {
+        let (_tx, rx) = crossbeam_channel::unbounded();
+        StreamingSource::new(rx, sample_rate)
+    }
+
+    #[test]
+    fn ema_update_seeds_from_the_first_sample() {
+        let mut ema = None;
+        ema_update(&mut ema, 42.0);
+        assert_eq!(ema, Some(42.0));
+    }
+
+    #[test]
+    fn ema_update_blends_towards_new_samples() {
+        let mut ema = Some(1.0);
+        ema_update(&mut ema, 2.0);
+        // EMA_ALPHA = 0.2: 0.2 * 2.0 + 0.8 * 1.0 = 1.2
+        assert!((ema.unwrap() - 1.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recompute_target_falls_back_to_the_default_period_with_no_data() {
+        let mut src = source(24000);
+        src.recompute_target();
+        assert_eq!(
+            src.current_target_samples.load(Ordering::Relaxed),
+            DEFAULT_PERIOD_SAMPLES as usize
+        );
+    }
+
+    #[test]
+    fn recompute_target_tracks_arrival_jitter_once_its_bigger_than_a_period() {
+        let mut src = source(1000);
+        src.ema_period_samples = Some(MIN_PERIOD_SAMPLES);
+        src.ema_arrival_interval_secs = Some(0.5); // 0.5s * 1000Hz = 500 samples
+        src.recompute_target();
+        assert_eq!(src.current_target_samples.load(Ordering::Relaxed), 500);
+    }
+
+    #[test]
+    fn recompute_target_scales_with_the_growth_multiplier() {
+        let mut src = source(1000);
+        src.ema_period_samples = Some(MIN_PERIOD_SAMPLES);
+        src.ema_arrival_interval_secs = Some(0.5);
+        src.growth_multiplier = UNDERRUN_GROWTH_FACTOR;
+        src.recompute_target();
+        assert_eq!(
+            src.current_target_samples.load(Ordering::Relaxed),
+            (500.0 * UNDERRUN_GROWTH_FACTOR).round() as usize
+        );
+    }
+
+    #[test]
+    fn recompute_target_clamps_to_the_max_target_seconds() {
+        let mut src = source(1000);
+        src.ema_arrival_interval_secs = Some(1000.0); // absurdly high jitter
+        src.recompute_target();
+        assert_eq!(
+            src.current_target_samples.load(Ordering::Relaxed),
+            1000 * MAX_TARGET_SECONDS as usize
+        );
+    }
+
+    #[test]
+    fn note_clean_drain_decays_the_growth_multiplier_after_an_interval() {
+        let mut src = source(1000);
+        src.growth_multiplier = 2.0;
+        for _ in 0..DECAY_INTERVAL_SAMPLES {
+            src.note_clean_drain();
+        }
+        assert!((src.growth_multiplier - 2.0 * TARGET_DECAY_FACTOR).abs() < 1e-9);
+        assert_eq!(src.clean_since_decay, 0);
+    }
+
+    #[test]
+    fn note_clean_drain_floors_the_growth_multiplier_at_one() {
+        let mut src = source(1000);
+        src.growth_multiplier = 1.0;
+        for _ in 0..DECAY_INTERVAL_SAMPLES {
+            src.note_clean_drain();
+        }
+        assert_eq!(src.growth_multiplier, 1.0);
+    }
+
+    #[test]
+    fn note_clean_drain_does_nothing_before_the_decay_interval_elapses() {
+        let mut src = source(1000);
+        src.growth_multiplier = 2.0;
+        for _ in 0..DECAY_INTERVAL_SAMPLES - 1 {
+            src.note_clean_drain();
+        }
+        assert_eq!(src.growth_multiplier, 2.0);
+    }
+}
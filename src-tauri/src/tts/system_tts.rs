@@ -0,0 +1,134 @@
+//! OS-native text-to-speech, used as an instant, zero-download fallback
+//! while Echo's multi-gigabyte model downloads in the background.
+//!
+//! Wraps the `tts` crate, which speaks through each platform's built-in
+//! engine: SAPI/WinRT on Windows, `AVSpeechSynthesizer` on macOS, Speech
+//! Dispatcher on Linux. Unlike Echo, the OS does its own audio playback --
+//! there's no `StreamingSource`/`AudioBackend` in this path.
+
+use crate::tts::Voice;
+use std::sync::Mutex;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SystemError {
+    #[error("Failed to initialize OS speech engine: {0}")]
+    InitError(String),
+    #[error("OS speech engine error: {0}")]
+    SpeechError(String),
+}
+
+/// Thin wrapper around the platform's native TTS engine.
+///
+/// Mirrors `EchoManager`'s lazy-init-on-first-use shape, but constructing
+/// the OS engine is cheap and synchronous, so there's no separate
+/// `initialize()` step -- every method brings it up on first use.
+pub struct SystemManager {
+    tts: Mutex<Option<tts::Tts>>,
+}
+
+impl SystemManager {
+    pub fn new() -> Self {
+        Self {
+            tts: Mutex::new(None),
+        }
+    }
+
+    fn with_tts<T>(
+        &self,
+        f: impl FnOnce(&mut tts::Tts) -> Result<T, tts::Error>,
+    ) -> Result<T, SystemError> {
+        let mut guard = self
+            .tts
+            .lock()
+            .map_err(|e| SystemError::InitError(e.to_string()))?;
+        if guard.is_none() {
+            *guard = Some(tts::Tts::default().map_err(|e| SystemError::InitError(e.to_string()))?);
+        }
+        f(guard.as_mut().expect("just initialized above"))
+            .map_err(|e| SystemError::SpeechError(e.to_string()))
+    }
+
+    /// Speak `text` at the given playback speed (`1.0` = the voice's normal
+    /// rate, matching the 0.5-2.0 range used elsewhere in this app),
+    /// interrupting whatever is currently being spoken.
+    pub fn speak(&self, text: &str, speed: f32) -> Result<(), SystemError> {
+        let text = text.to_string();
+        self.with_tts(|tts| {
+            if tts.supported_features().rate {
+                let (min, max, normal) = (tts.min_rate(), tts.max_rate(), tts.normal_rate());
+                let target = if speed >= 1.0 {
+                    normal + (max - normal) * (speed - 1.0).min(1.0)
+                } else {
+                    normal - (normal - min) * (1.0 - speed).min(1.0)
+                };
+                tts.set_rate(target)?;
+            }
+            tts.speak(text, true)?;
+            Ok(())
+        })
+    }
+
+    /// Stop whatever is currently being spoken.
+    pub fn stop(&self) -> Result<(), SystemError> {
+        self.with_tts(|tts| tts.stop().map(|_| ()))
+    }
+
+    /// Pause speech, where the platform supports it.
+    ///
+    /// Most backends the `tts` crate drives don't expose true pause/resume
+    /// (its `pause_resume` feature flag is usually unset), so this falls
+    /// back to a full stop -- `resume` then has nothing to resume.
+    pub fn pause(&self) -> Result<(), SystemError> {
+        self.with_tts(|tts| {
+            if tts.supported_features().pause_resume {
+                tts.pause()?;
+            } else {
+                tts.stop()?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn resume(&self) -> Result<(), SystemError> {
+        self.with_tts(|tts| {
+            if tts.supported_features().pause_resume {
+                tts.resume()?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Whether the OS voice is currently speaking. `false` if the platform
+    /// can't report it.
+    pub fn is_speaking(&self) -> bool {
+        self.with_tts(|tts| {
+            if tts.supported_features().is_speaking {
+                tts.is_speaking()
+            } else {
+                Ok(false)
+            }
+        })
+        .unwrap_or(false)
+    }
+
+    /// Enumerate the OS voices installed on this machine.
+    pub fn list_voices(&self) -> Result<Vec<Voice>, SystemError> {
+        self.with_tts(|tts| {
+            let voices = tts.voices()?;
+            Ok(voices
+                .into_iter()
+                .map(|v| Voice {
+                    id: v.id().to_string(),
+                    name: v.name().to_string(),
+                    language: v.language().to_string(),
+                })
+                .collect())
+        })
+    }
+}
+
+impl Default for SystemManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
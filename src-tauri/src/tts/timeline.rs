@@ -0,0 +1,109 @@
+//! Word-level audio/text alignment for karaoke-style highlighting.
+//!
+//! Maps a live playback sample position back to the word being spoken by
+//! precomputing a schedule that distributes the chunk's total audio duration
+//! across its words, proportionally to each word's weighted length. Borrows
+//! the "enqueue items as they stabilize" idea from streaming transcription:
+//! while generation is still in flight, `estimated` builds the schedule from
+//! a rough chars-per-second guess, and `rescale` replaces it with the real
+//! one once the total sample count is known.
+
+use crate::epub::Word;
+
+/// Rough speaking rate used to estimate total duration before generation
+/// finishes, in characters per second.
+const ESTIMATED_CHARS_PER_SECOND: f64 = 15.0;
+
+/// Extra pause weight (in "characters") added after sentence-ending
+/// punctuation, so the schedule reserves more time for the pause there.
+const SENTENCE_PAUSE_WEIGHT: f64 = 8.0;
+
+/// Extra pause weight added after commas/semicolons.
+const CLAUSE_PAUSE_WEIGHT: f64 = 3.0;
+
+/// One word's span in the audio, in samples.
+#[derive(Debug, Clone, Copy)]
+struct WordSpan {
+    end_sample: usize,
+}
+
+/// Maps playback position (in samples) to a word index for karaoke
+/// highlighting.
+pub struct Timeline {
+    schedule: Vec<WordSpan>,
+}
+
+impl Timeline {
+    /// Build a schedule distributing `total_samples` across `words`,
+    /// proportionally to each word's weighted length.
+    pub fn new(words: &[Word], total_samples: usize) -> Self {
+        Self {
+            schedule: build_schedule(words, total_samples),
+        }
+    }
+
+    /// Build a schedule using an estimated total duration
+    /// (`ESTIMATED_CHARS_PER_SECOND`), for use before the real generated
+    /// length is known. Call `rescale` once it is.
+    pub fn estimated(words: &[Word], sample_rate: u32) -> Self {
+        let total_chars: f64 = words.iter().map(|w| word_weight(w)).sum();
+        let estimated_secs = total_chars / ESTIMATED_CHARS_PER_SECOND;
+        let estimated_samples = (estimated_secs * sample_rate as f64) as usize;
+        Self::new(words, estimated_samples)
+    }
+
+    /// Rebuild the schedule against the real total sample count, once known
+    /// (e.g. when generation finishes).
+    pub fn rescale(&mut self, words: &[Word], total_samples: usize) {
+        self.schedule = build_schedule(words, total_samples);
+    }
+
+    /// The word index being spoken at `samples_played`, or `None` if the
+    /// schedule has no words. Clamped to the last word once playback runs
+    /// past the schedule's end (e.g. the real total overran the estimate).
+    pub fn current_word_index(&self, samples_played: usize) -> Option<usize> {
+        if self.schedule.is_empty() {
+            return None;
+        }
+        let idx = self
+            .schedule
+            .partition_point(|span| span.end_sample <= samples_played);
+        Some(idx.min(self.schedule.len() - 1))
+    }
+}
+
+/// Character-length weight for a word, plus extra weight modeling the pause
+/// after sentence-ending or clause-separating punctuation.
+fn word_weight(word: &Word) -> f64 {
+    let mut weight = word.text.chars().count().max(1) as f64;
+    match word.text.chars().last() {
+        Some(c) if ".?!".contains(c) => weight += SENTENCE_PAUSE_WEIGHT,
+        Some(c) if ",;".contains(c) => weight += CLAUSE_PAUSE_WEIGHT,
+        _ => {}
+    }
+    weight
+}
+
+/// Distribute `total_samples` across `words` proportionally to each word's
+/// weight, producing a contiguous, non-overlapping schedule.
+fn build_schedule(words: &[Word], total_samples: usize) -> Vec<WordSpan> {
+    if words.is_empty() || total_samples == 0 {
+        return Vec::new();
+    }
+    let weights: Vec<f64> = words.iter().map(word_weight).collect();
+    let total_weight: f64 = weights.iter().sum();
+
+    let mut schedule = Vec::with_capacity(words.len());
+    let mut acc_samples = 0usize;
+    for (i, weight) in weights.iter().enumerate() {
+        let end_sample = if i + 1 == weights.len() {
+            total_samples
+        } else {
+            ((acc_samples as f64) + (weight / total_weight) * total_samples as f64) as usize
+        };
+        let end_sample = end_sample.max(acc_samples);
+        schedule.push(WordSpan { end_sample });
+        acc_samples = end_sample;
+    }
+    schedule
+}